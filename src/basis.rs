@@ -0,0 +1,144 @@
+//! Orthonormal coordinate frames.
+//!
+//! Provides [`Basis3`], a named tangent/bitangent/normal frame for moving
+//! vectors between world space and a local (e.g. tangent) space.
+
+use crate::{Mat3, Vec3};
+use derive_more::Constructor;
+
+/// A 3D coordinate frame made up of three perpendicular unit vectors.
+///
+/// In local coordinates, `x` runs along the tangent, `y` along the bitangent
+/// and `z` along the normal.
+///
+/// # Examples
+/// ```
+/// use lars::{Basis3, Vec3};
+/// let basis = Basis3::from_normal(Vec3::new(0.0, 0.0, 2.0));
+/// assert_eq!(basis.to_local(Vec3::UNIT_Z), Vec3::UNIT_Z);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Constructor)]
+pub struct Basis3 {
+    /// The local `x` axis.
+    pub tangent: Vec3,
+    /// The local `y` axis.
+    pub bitangent: Vec3,
+    /// The local `z` axis.
+    pub normal: Vec3,
+}
+
+impl Basis3 {
+    /// Builds a right-handed frame around the normal `n`, picking the tangent
+    /// and bitangent with [`Vec3::orthonormal_basis`].
+    ///
+    /// `n` doesn't need to be unit length, but should be non-zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Basis3, Vec3};
+    /// let basis = Basis3::from_normal(Vec3::UNIT_Y);
+    /// assert_eq!(basis.tangent.cross(&basis.bitangent), basis.normal);
+    /// ```
+    pub fn from_normal(n: Vec3) -> Basis3 {
+        let (tangent, bitangent) = n.orthonormal_basis();
+        Basis3 {
+            tangent,
+            bitangent,
+            normal: n.normalize(),
+        }
+    }
+
+    /// Returns the matrix whose **columns** are the tangent, bitangent and
+    /// normal.
+    ///
+    /// Multiplying it by local coordinates gives the world-space vector, the
+    /// same as [`Basis3::to_world`].
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Basis3, Vec3};
+    /// let basis = Basis3::new(Vec3::UNIT_Y, Vec3::UNIT_Z, Vec3::UNIT_X);
+    /// let v = Vec3::new(2.0, 3.0, 1.0);
+    /// assert_eq!(basis.to_mat3() * v, basis.to_world(v));
+    /// ```
+    pub fn to_mat3(&self) -> Mat3 {
+        Mat3::from_cols_array([
+            [self.tangent.x, self.tangent.y, self.tangent.z],
+            [self.bitangent.x, self.bitangent.y, self.bitangent.z],
+            [self.normal.x, self.normal.y, self.normal.z],
+        ])
+    }
+
+    /// Converts a world-space vector into this frame's local coordinates, by
+    /// projecting it onto each axis.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Basis3, Vec3};
+    /// let basis = Basis3::new(Vec3::UNIT_Y, Vec3::UNIT_Z, Vec3::UNIT_X);
+    /// assert_eq!(basis.to_local(Vec3::new(1.0, 2.0, 3.0)), Vec3::new(2.0, 3.0, 1.0));
+    /// ```
+    pub fn to_local(&self, world: Vec3) -> Vec3 {
+        Vec3::new(
+            world.dot(&self.tangent),
+            world.dot(&self.bitangent),
+            world.dot(&self.normal),
+        )
+    }
+
+    /// Converts local coordinates in this frame back into a world-space vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Basis3, Vec3};
+    /// let basis = Basis3::new(Vec3::UNIT_Y, Vec3::UNIT_Z, Vec3::UNIT_X);
+    /// assert_eq!(basis.to_world(Vec3::new(2.0, 3.0, 1.0)), Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn to_world(&self, local: Vec3) -> Vec3 {
+        local.x * self.tangent + local.y * self.bitangent + local.z * self.normal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_from_normal_is_orthonormal() {
+        for n in [Vec3::UNIT_X, -Vec3::UNIT_Z, Vec3::new(1.0, -2.0, 0.5)] {
+            let basis = Basis3::from_normal(n);
+            assert_eq!(basis.normal, n.normalize());
+            assert_abs_diff_eq!(basis.tangent.mag(), 1.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(basis.bitangent.mag(), 1.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(basis.tangent.dot(&basis.normal), 0.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(basis.bitangent.dot(&basis.normal), 0.0, epsilon = 1e-12);
+            assert_eq!(basis.tangent.cross(&basis.bitangent), basis.normal);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let basis = Basis3::from_normal(Vec3::new(0.3, 1.0, -0.7));
+        let v = Vec3::new(4.0, -1.5, 2.0);
+        assert_eq!(basis.to_world(basis.to_local(v)), v);
+        assert_eq!(basis.to_local(basis.to_world(v)), v);
+        assert_abs_diff_eq!(basis.to_local(v).mag(), v.mag(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_normal_maps_to_local_z() {
+        let n = Vec3::new(-2.0, 1.0, 1.0);
+        let basis = Basis3::from_normal(n);
+        assert_eq!(basis.to_local(n), Vec3::new(0.0, 0.0, n.mag()));
+    }
+
+    #[test]
+    fn test_to_mat3_matches_to_world() {
+        let basis = Basis3::from_normal(Vec3::new(1.0, 2.0, -0.5));
+        let v = Vec3::new(-3.0, 0.5, 2.0);
+        assert_eq!(basis.to_mat3() * v, basis.to_world(v));
+        assert_eq!(basis.to_mat3().transpose() * v, basis.to_local(v));
+        assert!(basis.to_mat3().is_rotation());
+    }
+}
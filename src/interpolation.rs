@@ -0,0 +1,276 @@
+//! Interpolation utilities.
+//!
+//! Free functions for blending between several values at once, built on the
+//! single-step [`lerp`] functions.
+
+use crate::{lerp, Point2D, Point3D, Vec3};
+
+/// **Bilinearly interpolates** between four corner values.
+///
+/// The corners are named by their `(x, y)` position in the unit square, so `v10`
+/// is the value at `x = 1, y = 0`. The interpolation is done along `x` first,
+/// then along `y`.
+///
+/// # Examples
+/// ```
+/// use lars::{bilerp, Vec3};
+/// let v = bilerp(Vec3::ZERO, Vec3::UNIT_X, Vec3::UNIT_Y, Vec3::ONE, 0.5, 0.5);
+/// assert_eq!(v, Vec3::new(0.5, 0.5, 0.25));
+/// ```
+pub fn bilerp(v00: Vec3, v10: Vec3, v01: Vec3, v11: Vec3, tx: f64, ty: f64) -> Vec3 {
+    let bottom = v00.lerp(&v10, tx);
+    let top = v01.lerp(&v11, tx);
+    bottom.lerp(&top, ty)
+}
+
+/// **Bilinearly interpolates** between four scalar corner values.
+///
+/// See [`bilerp`] for the corner naming.
+///
+/// # Examples
+/// ```
+/// use lars::bilerp_scalar;
+/// assert_eq!(bilerp_scalar(0.0, 1.0, 2.0, 3.0, 0.5, 0.5), 1.5);
+/// ```
+pub fn bilerp_scalar(v00: f64, v10: f64, v01: f64, v11: f64, tx: f64, ty: f64) -> f64 {
+    let bottom = lerp(v00, v10, tx);
+    let top = lerp(v01, v11, tx);
+    lerp(bottom, top, ty)
+}
+
+/// Evaluates a **Catmull-Rom spline** segment at `t`.
+///
+/// The curve runs from `p1` (at `t = 0`) to `p2` (at `t = 1`), with `p0` and `p3`
+/// controlling the tangents at each end. Chaining segments over a list of points
+/// gives a smooth curve that passes through every point.
+///
+/// # Examples
+/// ```
+/// use lars::{catmull_rom, Point3D};
+/// let p0 = Point3D::new(0.0, 0.0, 0.0);
+/// let p1 = Point3D::new(1.0, 0.0, 0.0);
+/// let p2 = Point3D::new(2.0, 0.0, 0.0);
+/// let p3 = Point3D::new(3.0, 0.0, 0.0);
+/// assert_eq!(catmull_rom(p0, p1, p2, p3, 0.5), Point3D::new(1.5, 0.0, 0.0));
+/// ```
+pub fn catmull_rom(p0: Point3D, p1: Point3D, p2: Point3D, p3: Point3D, t: f64) -> Point3D {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    0.5 * (2.0 * p1
+        + t * (p2 - p0)
+        + t2 * (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3)
+        + t3 * (3.0 * p1 - p0 - 3.0 * p2 + p3))
+}
+
+/// Evaluates a 2D **Catmull-Rom spline** segment at `t`.
+///
+/// See [`catmull_rom`] for details.
+///
+/// # Examples
+/// ```
+/// use lars::{catmull_rom_2d, Point2D};
+/// let p0 = Point2D::new(0.0, 0.0);
+/// let p1 = Point2D::new(1.0, 1.0);
+/// let p2 = Point2D::new(2.0, 1.0);
+/// let p3 = Point2D::new(3.0, 0.0);
+/// assert_eq!(catmull_rom_2d(p0, p1, p2, p3, 0.0), p1);
+/// ```
+pub fn catmull_rom_2d(p0: Point2D, p1: Point2D, p2: Point2D, p3: Point2D, t: f64) -> Point2D {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    0.5 * (2.0 * p1
+        + t * (p2 - p0)
+        + t2 * (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3)
+        + t3 * (3.0 * p1 - p0 - 3.0 * p2 + p3))
+}
+
+/// Evaluates a **quadratic Bézier curve** at `t`, using de Casteljau's algorithm.
+///
+/// The curve starts at `a` (`t = 0`), ends at `c` (`t = 1`) and is pulled
+/// towards the control point `b`.
+///
+/// # Examples
+/// ```
+/// use lars::{bezier_quadratic, Point3D};
+/// let a = Point3D::new(0.0, 0.0, 0.0);
+/// let b = Point3D::new(1.0, 2.0, 0.0);
+/// let c = Point3D::new(2.0, 0.0, 0.0);
+/// assert_eq!(bezier_quadratic(a, b, c, 0.5), Point3D::new(1.0, 1.0, 0.0));
+/// ```
+pub fn bezier_quadratic(a: Point3D, b: Point3D, c: Point3D, t: f64) -> Point3D {
+    let ab = a.lerp(&b, t);
+    let bc = b.lerp(&c, t);
+    ab.lerp(&bc, t)
+}
+
+/// Evaluates a **cubic Bézier curve** at `t`, using de Casteljau's algorithm.
+///
+/// The curve starts at `a` (`t = 0`), ends at `d` (`t = 1`) and is pulled
+/// towards the control points `b` and `c`.
+///
+/// # Examples
+/// ```
+/// use lars::{bezier_cubic, Point3D};
+/// let a = Point3D::new(0.0, 0.0, 0.0);
+/// let d = Point3D::new(3.0, 0.0, 0.0);
+/// assert_eq!(bezier_cubic(a, Point3D::ONE, Point3D::ONE, d, 1.0), d);
+/// ```
+pub fn bezier_cubic(a: Point3D, b: Point3D, c: Point3D, d: Point3D, t: f64) -> Point3D {
+    let abc = bezier_quadratic(a, b, c, t);
+    let bcd = bezier_quadratic(b, c, d, t);
+    abc.lerp(&bcd, t)
+}
+
+/// Evaluates a 2D **quadratic Bézier curve** at `t`.
+///
+/// See [`bezier_quadratic`] for details.
+///
+/// # Examples
+/// ```
+/// use lars::{bezier_quadratic_2d, Point2D};
+/// let a = Point2D::new(0.0, 0.0);
+/// let b = Point2D::new(1.0, 2.0);
+/// let c = Point2D::new(2.0, 0.0);
+/// assert_eq!(bezier_quadratic_2d(a, b, c, 0.5), Point2D::new(1.0, 1.0));
+/// ```
+pub fn bezier_quadratic_2d(a: Point2D, b: Point2D, c: Point2D, t: f64) -> Point2D {
+    let ab = a.lerp(&b, t);
+    let bc = b.lerp(&c, t);
+    ab.lerp(&bc, t)
+}
+
+/// Evaluates a 2D **cubic Bézier curve** at `t`.
+///
+/// See [`bezier_cubic`] for details.
+///
+/// # Examples
+/// ```
+/// use lars::{bezier_cubic_2d, Point2D};
+/// let a = Point2D::new(0.0, 0.0);
+/// let d = Point2D::new(3.0, 0.0);
+/// assert_eq!(bezier_cubic_2d(a, Point2D::ONE, Point2D::ONE, d, 0.0), a);
+/// ```
+pub fn bezier_cubic_2d(a: Point2D, b: Point2D, c: Point2D, d: Point2D, t: f64) -> Point2D {
+    let abc = bezier_quadratic_2d(a, b, c, t);
+    let bcd = bezier_quadratic_2d(b, c, d, t);
+    abc.lerp(&bcd, t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    fn corners() -> [Vec3; 4] {
+        [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(0.0, 0.0, 3.0),
+            Vec3::new(4.0, 4.0, 4.0),
+        ]
+    }
+
+    #[test]
+    fn test_bilerp_corners() {
+        let [v00, v10, v01, v11] = corners();
+        assert_eq!(bilerp(v00, v10, v01, v11, 0.0, 0.0), v00);
+        assert_eq!(bilerp(v00, v10, v01, v11, 1.0, 0.0), v10);
+        assert_eq!(bilerp(v00, v10, v01, v11, 0.0, 1.0), v01);
+        assert_eq!(bilerp(v00, v10, v01, v11, 1.0, 1.0), v11);
+    }
+
+    #[test]
+    fn test_bilerp_center() {
+        let [v00, v10, v01, v11] = corners();
+        let average = (v00 + v10 + v01 + v11) / 4.0;
+        assert_eq!(bilerp(v00, v10, v01, v11, 0.5, 0.5), average);
+    }
+
+    #[test]
+    fn test_bilerp_scalar() {
+        assert_eq!(bilerp_scalar(1.0, 2.0, 3.0, 4.0, 0.0, 0.0), 1.0);
+        assert_eq!(bilerp_scalar(1.0, 2.0, 3.0, 4.0, 1.0, 0.0), 2.0);
+        assert_eq!(bilerp_scalar(1.0, 2.0, 3.0, 4.0, 0.0, 1.0), 3.0);
+        assert_eq!(bilerp_scalar(1.0, 2.0, 3.0, 4.0, 1.0, 1.0), 4.0);
+        assert_eq!(bilerp_scalar(1.0, 2.0, 3.0, 4.0, 0.5, 0.5), 2.5);
+    }
+
+    fn control_points() -> [Point3D; 5] {
+        [
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 2.0, 0.0),
+            Point3D::new(3.0, 3.0, 1.0),
+            Point3D::new(4.0, 1.0, 2.0),
+            Point3D::new(6.0, 0.0, 2.0),
+        ]
+    }
+
+    #[test]
+    fn test_catmull_rom_endpoints() {
+        let [p0, p1, p2, p3, _] = control_points();
+        assert_eq!(catmull_rom(p0, p1, p2, p3, 0.0), p1);
+        assert_eq!(catmull_rom(p0, p1, p2, p3, 1.0), p2);
+    }
+
+    #[test]
+    fn test_catmull_rom_smooth_join() {
+        // Two neighbouring segments meet at p2 with the same tangent.
+        let [p0, p1, p2, p3, p4] = control_points();
+        let h = 1e-6;
+        let end_of_first = catmull_rom(p0, p1, p2, p3, 1.0);
+        let start_of_second = catmull_rom(p1, p2, p3, p4, 0.0);
+        assert_eq!(end_of_first, start_of_second);
+
+        let tangent_in = (end_of_first - catmull_rom(p0, p1, p2, p3, 1.0 - h)) / h;
+        let tangent_out = (catmull_rom(p1, p2, p3, p4, h) - start_of_second) / h;
+        assert_abs_diff_eq!(tangent_in.dist(&tangent_out), 0.0, epsilon = 1e-4);
+        assert_abs_diff_eq!(tangent_in.dist(&((p3 - p1) / 2.0)), 0.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_catmull_rom_2d() {
+        let p0 = Point2D::new(0.0, 0.0);
+        let p1 = Point2D::new(1.0, 2.0);
+        let p2 = Point2D::new(3.0, 3.0);
+        let p3 = Point2D::new(4.0, 1.0);
+        assert_eq!(catmull_rom_2d(p0, p1, p2, p3, 0.0), p1);
+        assert_eq!(catmull_rom_2d(p0, p1, p2, p3, 1.0), p2);
+
+        let mid3 = catmull_rom(p0.extend(0.0), p1.extend(0.0), p2.extend(0.0), p3.extend(0.0), 0.3);
+        let mid2 = catmull_rom_2d(p0, p1, p2, p3, 0.3);
+        assert_eq!(Point2D::new(mid3.x, mid3.y), mid2);
+    }
+
+    #[test]
+    fn test_bezier_quadratic() {
+        let a = Point3D::new(0.0, 0.0, 0.0);
+        let b = Point3D::new(2.0, 4.0, 2.0);
+        let c = Point3D::new(4.0, 0.0, 0.0);
+        assert_eq!(bezier_quadratic(a, b, c, 0.0), a);
+        assert_eq!(bezier_quadratic(a, b, c, 1.0), c);
+        // B(0.5) = a/4 + b/2 + c/4
+        assert_eq!(bezier_quadratic(a, b, c, 0.5), Point3D::new(2.0, 2.0, 1.0));
+    }
+
+    #[test]
+    fn test_bezier_cubic() {
+        let [a, b, c, d, _] = control_points();
+        assert_eq!(bezier_cubic(a, b, c, d, 0.0), a);
+        assert_eq!(bezier_cubic(a, b, c, d, 1.0), d);
+        // B(0.5) = (a + 3b + 3c + d) / 8
+        assert_eq!(bezier_cubic(a, b, c, d, 0.5), (a + 3.0 * b + 3.0 * c + d) / 8.0);
+    }
+
+    #[test]
+    fn test_bezier_2d() {
+        let a = Point2D::new(0.0, 0.0);
+        let b = Point2D::new(2.0, 4.0);
+        let c = Point2D::new(4.0, 0.0);
+        let d = Point2D::new(6.0, 2.0);
+        assert_eq!(bezier_quadratic_2d(a, b, c, 0.0), a);
+        assert_eq!(bezier_quadratic_2d(a, b, c, 1.0), c);
+        assert_eq!(bezier_quadratic_2d(a, b, c, 0.5), Point2D::new(2.0, 2.0));
+        assert_eq!(bezier_cubic_2d(a, b, c, d, 0.0), a);
+        assert_eq!(bezier_cubic_2d(a, b, c, d, 1.0), d);
+    }
+}
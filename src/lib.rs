@@ -1,5 +1,26 @@
+mod basis;
+mod interpolation;
 mod matrix;
+mod points;
+mod polygon;
+pub mod prelude;
+mod scalar;
+mod segment;
+mod sphere;
+mod transform;
+mod triangle;
+mod units;
 mod vector;
 
+pub use basis::*;
+pub use interpolation::*;
 pub use matrix::*;
+pub use points::*;
+pub use polygon::*;
+pub use scalar::*;
+pub use segment::*;
+pub use sphere::*;
+pub use transform::*;
+pub use triangle::*;
+pub use units::*;
 pub use vector::*;
@@ -7,8 +7,8 @@
 //! This type is designed to pair naturally with the [`Vec2`] struct
 //! for 2D linear transformations.
 
-use crate::Vec2;
-use derive_more::{Add, Constructor, Sub, Div};
+use crate::{Mat3, Radians, Vec2};
+use derive_more::{Add, AddAssign, Constructor, Sub, SubAssign, Div};
 use std::ops::Mul;
 
 /// A 2×2 matrix of `f64` values.
@@ -29,7 +29,7 @@ use std::ops::Mul;
 ///
 /// assert_eq!(m * v, v);
 /// ```
-#[derive(Constructor, Copy, Clone, Debug, Add, Sub, PartialOrd, Div)]
+#[derive(Constructor, Copy, Clone, Debug, Add, Sub, AddAssign, SubAssign, PartialOrd, Div)]
 pub struct Mat2 {
     /// Top-left element.
     pub a: f64,
@@ -69,6 +69,224 @@ impl Mat2 {
     /// ```
     pub const ZERO: Mat2 = Mat2::new(0.0, 0.0, 0.0, 0.0);
 
+    /// Returns the matrix that **rotates** vectors anticlockwise by `angle`:
+    ///
+    /// ```text
+    /// | cos θ  -sin θ |
+    /// | sin θ   cos θ |
+    /// ```
+    ///
+    /// The angle can be given as [`Radians`], [`Degrees`](crate::Degrees), or a
+    /// bare `f64` in radians.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Degrees, Mat2, Vec2};
+    /// let m = Mat2::rotation(std::f64::consts::FRAC_PI_2);
+    /// assert_eq!(m * Vec2::UNIT_X, Vec2::UNIT_Y);
+    /// assert_eq!(Mat2::rotation(Degrees(90.0)), m);
+    /// ```
+    pub fn rotation(angle: impl Into<Radians>) -> Mat2 {
+        let (sin, cos) = angle.into().0.sin_cos();
+        Mat2::new(cos, -sin, sin, cos)
+    }
+
+    /// Returns the rotation matrix that turns the direction of `a` onto the
+    /// direction of `b`.
+    ///
+    /// The signed angle between them is found from the scalar cross and dot
+    /// products, `atan2(a × b, a · b)`. If either vector has zero length there is
+    /// no direction to align, and the identity is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat2, Vec2};
+    /// let m = Mat2::rotation_between(Vec2::UNIT_X, Vec2::new(0.0, 3.0));
+    /// assert_eq!(m * Vec2::UNIT_X, Vec2::UNIT_Y);
+    /// ```
+    pub fn rotation_between(a: Vec2, b: Vec2) -> Mat2 {
+        if a.approx_zero() || b.approx_zero() {
+            return Mat2::IDENTITY;
+        }
+        Mat2::rotation(a.cross(&b).atan2(a.dot(&b)))
+    }
+
+    /// Returns the matrix that **scales** both axes by the same factor `s`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat2, Vec2};
+    /// assert_eq!(Mat2::scale_uniform(3.0) * Vec2::ONE, Vec2::new(3.0, 3.0));
+    /// ```
+    pub fn scale_uniform(s: f64) -> Mat2 {
+        Mat2::new(s, 0.0, 0.0, s)
+    }
+
+    /// Returns the matrix with `d` on its **diagonal** and zeros elsewhere.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat2, Vec2};
+    /// let m = Mat2::from_diagonal(Vec2::new(2.0, 3.0));
+    /// assert_eq!(m * Vec2::ONE, Vec2::new(2.0, 3.0));
+    /// ```
+    pub fn from_diagonal(d: Vec2) -> Mat2 {
+        Mat2::new(d.x, 0.0, 0.0, d.y)
+    }
+
+    /// Returns the **diagonal** elements of the matrix, `(a, d)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat2, Vec2};
+    /// assert_eq!(Mat2::new(1.0, 2.0, 3.0, 4.0).diagonal(), Vec2::new(1.0, 4.0));
+    /// ```
+    pub fn diagonal(&self) -> Vec2 {
+        Vec2::new(self.a, self.d)
+    }
+
+    /// Returns `true` if the matrix is the identity (within epsilon), e.g. to
+    /// skip applying a transform that does nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat2;
+    /// assert!(Mat2::IDENTITY.is_identity());
+    /// assert!(!Mat2::scale_uniform(2.0).is_identity());
+    /// ```
+    pub fn is_identity(&self) -> bool {
+        *self == Mat2::IDENTITY
+    }
+
+    /// Returns `true` if both elements off the diagonal are zero (within
+    /// epsilon), as for a pure axis-aligned scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat2, Vec2};
+    /// assert!(Mat2::from_diagonal(Vec2::new(2.0, 3.0)).is_diagonal());
+    /// assert!(!Mat2::rotation(0.5).is_diagonal());
+    /// ```
+    pub fn is_diagonal(&self) -> bool {
+        self.b.abs() < EPSILON && self.c.abs() < EPSILON
+    }
+
+    /// Extracts the upper-left 2×2 block of a [`Mat3`].
+    ///
+    /// For a 2D homogeneous transform this is the linear (rotation/scale) part,
+    /// with the translation discarded.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat2, Mat3};
+    /// let m = Mat3::new(1.0, 2.0, 5.0, 3.0, 4.0, 6.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(Mat2::from_mat3(m), Mat2::new(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    pub fn from_mat3(m: Mat3) -> Mat2 {
+        Mat2::new(m.a, m.b, m.d, m.e)
+    }
+
+    /// Builds a matrix from an array of **rows**, so `rows[r][c]` becomes the
+    /// element in row `r`, column `c`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat2;
+    /// let m = Mat2::from_rows_array([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(m, Mat2::new(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    pub fn from_rows_array(rows: [[f64; 2]; 2]) -> Mat2 {
+        let [[a, b], [c, d]] = rows;
+        Mat2::new(a, b, c, d)
+    }
+
+    /// Returns the elements of the matrix as an array of **rows**.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat2;
+    /// let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(m.to_rows_array(), [[1.0, 2.0], [3.0, 4.0]]);
+    /// ```
+    pub fn to_rows_array(&self) -> [[f64; 2]; 2] {
+        [[self.a, self.b], [self.c, self.d]]
+    }
+
+    /// Builds a matrix from an array of **columns**, so `cols[c][r]` becomes the
+    /// element in row `r`, column `c`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat2;
+    /// let m = Mat2::from_cols_array([[1.0, 3.0], [2.0, 4.0]]);
+    /// assert_eq!(m, Mat2::new(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    pub fn from_cols_array(cols: [[f64; 2]; 2]) -> Mat2 {
+        let [[a, c], [b, d]] = cols;
+        Mat2::new(a, b, c, d)
+    }
+
+    /// Returns the elements of the matrix as an array of **columns**.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat2;
+    /// let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(m.to_cols_array(), [[1.0, 3.0], [2.0, 4.0]]);
+    /// ```
+    pub fn to_cols_array(&self) -> [[f64; 2]; 2] {
+        [[self.a, self.c], [self.b, self.d]]
+    }
+
+    /// Applies a function `f` to each element of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat2;
+    /// let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(m.map(|x| x * x), Mat2::new(1.0, 4.0, 9.0, 16.0));
+    /// ```
+    pub fn map<F>(&self, f: F) -> Mat2
+    where
+        F: Fn(f64) -> f64,
+    {
+        Mat2::new(f(self.a), f(self.b), f(self.c), f(self.d))
+    }
+
+    /// Returns the **element-wise** (Hadamard) product of `self` and `other`.
+    ///
+    /// This is **not** matrix multiplication, use `*` for that.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat2;
+    /// let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(m.component_mul(&Mat2::IDENTITY), Mat2::new(1.0, 0.0, 0.0, 4.0));
+    /// ```
+    pub fn component_mul(&self, other: &Mat2) -> Mat2 {
+        Mat2::new(
+            self.a * other.a,
+            self.b * other.b,
+            self.c * other.c,
+            self.d * other.d,
+        )
+    }
+
+    /// Replaces every element with an absolute value below `eps` by exactly
+    /// `0.0`, cleaning up rounding noise such as `1e-17` left behind by a chain
+    /// of multiplications.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat2;
+    /// let m = Mat2::rotation(std::f64::consts::FRAC_PI_2);
+    /// assert_ne!(m.a, 0.0);
+    /// assert_eq!(m.snap_small_to_zero(1e-12).a, 0.0);
+    /// ```
+    pub fn snap_small_to_zero(&self, eps: f64) -> Mat2 {
+        self.map(|x| if x.abs() < eps { 0.0 } else { x })
+    }
+
     /// Returns the **determinant** of the matrix.
     ///
     /// Computed as:
@@ -106,21 +324,175 @@ impl Mat2 {
         if self.determinant() == 0.0 {
             panic!("Matrix is singular and cannot be inverted.");
         };
-        let rec_det = 1.0 / self.determinant();
 
-        rec_det * Mat2::new(self.d, -self.b, -self.c, self.a)
+        Mat2::new(self.d, -self.b, -self.c, self.a) / self.determinant()
+    }
+
+    /// Returns the **transpose** of the matrix, swapping rows and columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat2;
+    /// let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(m.transpose(), Mat2::new(1.0, 3.0, 2.0, 4.0));
+    /// ```
+    pub fn transpose(&self) -> Mat2 {
+        Mat2::new(self.a, self.c, self.b, self.d)
+    }
+
+    /// Returns the **trace** of the matrix, the sum of its diagonal elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat2;
+    /// let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(m.trace(), 5.0);
+    /// ```
+    pub fn trace(&self) -> f64 {
+        self.a + self.d
+    }
+
+    /// Returns the **eigenvalues** of the matrix, largest first.
+    ///
+    /// These are the roots of the characteristic polynomial:
+    /// \[
+    /// \lambda^2 - \operatorname{tr}(M)\lambda + \det(M) = 0
+    /// \]
+    /// The discriminant `(tr/2)² - det` is computed in the equivalent form
+    /// `((a - d)/2)² + bc`, which avoids cancellation when the eigenvalues are
+    /// nearly equal and is never negative for a symmetric matrix.
+    ///
+    /// Returns `None` if the eigenvalues are complex (e.g. for a rotation matrix).
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat2;
+    /// let m = Mat2::new(2.0, 0.0, 0.0, 3.0);
+    /// assert_eq!(m.eigenvalues(), Some((3.0, 2.0)));
+    /// ```
+    pub fn eigenvalues(&self) -> Option<(f64, f64)> {
+        let half_trace = self.trace() / 2.0;
+        let h = 0.5 * (self.a - self.d);
+        let discriminant = h * h + self.b * self.c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        Some((half_trace + root, half_trace - root))
+    }
+
+    /// Returns unit-length **eigenvectors** of the matrix, in the same order as
+    /// [`eigenvalues`](Mat2::eigenvalues).
+    ///
+    /// Returns `None` if the eigenvalues are complex.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat2, Vec2};
+    /// let m = Mat2::new(2.0, 0.0, 0.0, 3.0);
+    /// assert_eq!(m.eigenvectors(), Some((Vec2::UNIT_Y, Vec2::UNIT_X)));
+    /// ```
+    pub fn eigenvectors(&self) -> Option<(Vec2, Vec2)> {
+        let (l1, l2) = self.eigenvalues()?;
+
+        // Each row of (M - λI) is orthogonal to the eigenvector, so use whichever
+        // off-diagonal element is non-zero, relative to the scale of the matrix,
+        // to build it.
+        let largest = [self.a, self.b, self.c, self.d]
+            .iter()
+            .fold(0.0, |m: f64, x| m.max(x.abs()));
+        let tolerance = EPSILON * largest;
+        let eigenvector = |l: f64, fallback: Vec2| {
+            if self.c.abs() > tolerance {
+                Vec2::new(l - self.d, self.c).normalize()
+            } else if self.b.abs() > tolerance {
+                Vec2::new(self.b, l - self.a).normalize()
+            } else {
+                fallback
+            }
+        };
+
+        // For a diagonal matrix the eigenvectors are the axes, matched to whichever
+        // diagonal element each eigenvalue came from.
+        let (first_axis, second_axis) = if self.a >= self.d {
+            (Vec2::UNIT_X, Vec2::UNIT_Y)
+        } else {
+            (Vec2::UNIT_Y, Vec2::UNIT_X)
+        };
+
+        Some((eigenvector(l1, first_axis), eigenvector(l2, second_axis)))
+    }
+
+    /// Returns the **Frobenius norm** of the matrix, the square root of the sum
+    /// of its squared elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat2;
+    /// let m = Mat2::new(1.0, 1.0, 1.0, 1.0);
+    /// assert_eq!(m.frobenius_norm(), 2.0);
+    /// ```
+    pub fn frobenius_norm(&self) -> f64 {
+        (self.a * self.a + self.b * self.b + self.c * self.c + self.d * self.d).sqrt()
+    }
+
+    /// Returns the **singular value decomposition** `(U, S, V)` of the matrix,
+    /// such that `M = U * diag(S) * Vᵀ`.
+    ///
+    /// `U` and `V` are orthogonal and `S` holds the singular values, largest
+    /// first and never negative. Read right to left, this splits any 2D linear
+    /// transform into a rotation, a non-uniform scale along the axes, and a
+    /// second rotation (or reflection, if `det(M) < 0`).
+    ///
+    /// For 2×2 matrices there is a closed form. With
+    /// `E = (a + d)/2`, `F = (a - d)/2`, `G = (c + b)/2` and `H = (c - b)/2`,
+    /// the singular values are `√(E² + H²) ± √(F² + G²)`, and the rotation
+    /// angles come from `atan2(G, F)` and `atan2(H, E)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat2, Vec2};
+    /// let m = Mat2::new(3.0, 0.0, 0.0, -2.0);
+    /// let (u, s, v) = m.svd();
+    /// assert_eq!(s, Vec2::new(3.0, 2.0));
+    /// assert_eq!(u * Mat2::from_diagonal(s) * v.transpose(), m);
+    /// ```
+    pub fn svd(&self) -> (Mat2, Vec2, Mat2) {
+        let e = 0.5 * (self.a + self.d);
+        let f = 0.5 * (self.a - self.d);
+        let g = 0.5 * (self.c + self.b);
+        let h = 0.5 * (self.c - self.b);
+        let q = (e * e + h * h).sqrt();
+        let r = (f * f + g * g).sqrt();
+
+        let a1 = g.atan2(f);
+        let a2 = h.atan2(e);
+        let u = Mat2::rotation(0.5 * (a2 + a1));
+        let v = Mat2::rotation(0.5 * (a1 - a2));
+        let (s1, s2) = (q + r, q - r);
+
+        // A negative second value is folded into `U` as a reflection.
+        if s2 < 0.0 {
+            (u * Mat2::from_diagonal(Vec2::new(1.0, -1.0)), Vec2::new(s1, -s2), v)
+        } else {
+            (u, Vec2::new(s1, s2), v)
+        }
     }
 }
 
 /// Implements **matrix–scalar multiplication** (`Mat2 * f64`).
 ///
-/// Each element of the matrix is scaled by the scalar.
+/// Each element of the matrix is scaled by the scalar. Division by a scalar
+/// (`Mat2 / f64`) is derived and divides each element the same way. There is
+/// deliberately no `f64 / Mat2`; use [`inverse`](Mat2::inverse) for that.
 ///
 /// # Examples
 /// ```
 /// use lars::Mat2;
 /// let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
 /// assert_eq!(m * 2.0, Mat2::new(2.0, 4.0, 6.0, 8.0));
+/// assert_eq!(m / 2.0, Mat2::new(0.5, 1.0, 1.5, 2.0));
 /// ```
 impl Mul<f64> for Mat2 {
     type Output = Mat2;
@@ -201,9 +573,144 @@ impl Mul<Mat2> for Mat2 {
     }
 }
 
+/// Implements **matrix–vector multiplication** by reference (`&Mat2 * &Vec2`).
+///
+/// # Examples
+/// ```
+/// use lars::{Mat2, Vec2};
+/// let m = Mat2::IDENTITY;
+/// let v = Vec2::ONE;
+/// assert_eq!(&m * &v, v);
+/// ```
+impl Mul<&Vec2> for &Mat2 {
+    type Output = Vec2;
+    fn mul(self, v: &Vec2) -> Vec2 {
+        *self * *v
+    }
+}
+
+/// Implements **matrix–vector multiplication** with a borrowed matrix (`&Mat2 * Vec2`).
+impl Mul<Vec2> for &Mat2 {
+    type Output = Vec2;
+    fn mul(self, v: Vec2) -> Vec2 {
+        *self * v
+    }
+}
+
+/// Implements **matrix–matrix multiplication** by reference (`&Mat2 * &Mat2`).
+///
+/// # Examples
+/// ```
+/// use lars::Mat2;
+/// let a = Mat2::IDENTITY;
+/// let b = Mat2::IDENTITY * 2.0;
+/// assert_eq!(&a * &b, b);
+/// ```
+impl Mul<&Mat2> for &Mat2 {
+    type Output = Mat2;
+    fn mul(self, m: &Mat2) -> Mat2 {
+        *self * *m
+    }
+}
+
+/// Implements **matrix–matrix multiplication** with a borrowed left-hand side (`&Mat2 * Mat2`).
+impl Mul<Mat2> for &Mat2 {
+    type Output = Mat2;
+    fn mul(self, m: Mat2) -> Mat2 {
+        *self * m
+    }
+}
+
+/// Implements **matrix–matrix multiplication** with a borrowed right-hand side (`Mat2 * &Mat2`).
+impl Mul<&Mat2> for Mat2 {
+    type Output = Mat2;
+    fn mul(self, m: &Mat2) -> Mat2 {
+        self * *m
+    }
+}
+
+/// Compares two [`Mat2`]s element by element, within an absolute tolerance.
+///
+/// Available with the `approx` feature.
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Mat2 {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        EPSILON
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.a.abs_diff_eq(&other.a, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+            && self.c.abs_diff_eq(&other.c, epsilon)
+            && self.d.abs_diff_eq(&other.d, epsilon)
+    }
+}
+
+/// Compares two [`Mat2`]s element by element, within a relative tolerance.
+///
+/// Available with the `approx` feature.
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Mat2 {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.a.relative_eq(&other.a, epsilon, max_relative)
+            && self.b.relative_eq(&other.b, epsilon, max_relative)
+            && self.c.relative_eq(&other.c, epsilon, max_relative)
+            && self.d.relative_eq(&other.d, epsilon, max_relative)
+    }
+}
+
+/// Converts from a column-major `mint` matrix. Available with the `mint` feature.
+///
+/// Each `mint` column becomes a column of the row-major [`Mat2`].
+#[cfg(feature = "mint")]
+impl From<mint::ColumnMatrix2<f64>> for Mat2 {
+    fn from(m: mint::ColumnMatrix2<f64>) -> Self {
+        Mat2::new(m.x.x, m.y.x, m.x.y, m.y.y)
+    }
+}
+
+/// Converts to a column-major `mint` matrix. Available with the `mint` feature.
+#[cfg(feature = "mint")]
+impl From<Mat2> for mint::ColumnMatrix2<f64> {
+    fn from(m: Mat2) -> Self {
+        mint::ColumnMatrix2 {
+            x: mint::Vector2 { x: m.a, y: m.c },
+            y: mint::Vector2 { x: m.b, y: m.d },
+        }
+    }
+}
+
+/// Returns [`Mat2::ZERO`]. Available with the `num-traits` feature.
+#[cfg(feature = "num-traits")]
+impl num_traits::Zero for Mat2 {
+    fn zero() -> Self {
+        Mat2::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Mat2::ZERO
+    }
+}
+
+/// Returns [`Mat2::IDENTITY`]. Available with the `num-traits` feature.
+#[cfg(feature = "num-traits")]
+impl num_traits::One for Mat2 {
+    fn one() -> Self {
+        Mat2::IDENTITY
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use approx::assert_abs_diff_eq;
+    use crate::Degrees;
 
     #[test]
     fn test_add() {
@@ -263,4 +770,255 @@ mod tests {
         });
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_trace() {
+        let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(m.trace(), 5.0);
+    }
+
+    #[test]
+    fn test_eigenvalues_diagonal() {
+        let m = Mat2::new(5.0, 0.0, 0.0, -1.0);
+        assert_eq!(m.eigenvalues(), Some((5.0, -1.0)));
+        assert_eq!(m.eigenvectors(), Some((Vec2::UNIT_X, Vec2::UNIT_Y)));
+    }
+
+    #[test]
+    fn test_eigen_symmetric() {
+        let m = Mat2::new(2.0, 1.0, 1.0, 2.0);
+        assert_eq!(m.eigenvalues(), Some((3.0, 1.0)));
+
+        let (v1, v2) = m.eigenvectors().unwrap();
+        assert_eq!(v1, Vec2::new(1.0, 1.0).normalize());
+        assert_eq!(v2, Vec2::new(-1.0, 1.0).normalize());
+        assert_eq!(m * v1, 3.0 * v1);
+        assert_eq!(m * v2, 1.0 * v2);
+    }
+
+    #[test]
+    fn test_eigenvectors_small_scale() {
+        let m = Mat2::new(2.0, 1.0, 1.0, 2.0) * 1e-12;
+        let (v1, v2) = m.eigenvectors().unwrap();
+        assert_eq!(v1, Vec2::new(1.0, 1.0).normalize());
+        assert_eq!(v2, Vec2::new(-1.0, 1.0).normalize());
+    }
+
+    #[test]
+    fn test_eigen_nearly_equal_symmetric() {
+        let m = Mat2::new(2.2874560591713897, 4.82e-10, 4.82e-10, 2.287456059171862);
+        let (l1, l2) = m.eigenvalues().unwrap();
+        assert!(l1 >= l2);
+        assert_abs_diff_eq!(l1 + l2, m.trace(), epsilon = 1e-12);
+        let (v1, v2) = m.eigenvectors().unwrap();
+        assert_abs_diff_eq!(v1.dot(&v2), 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_eigenvalues_complex() {
+        let rotation = Mat2::new(0.0, -1.0, 1.0, 0.0);
+        assert_eq!(rotation.eigenvalues(), None);
+        assert_eq!(rotation.eigenvectors(), None);
+    }
+
+    #[test]
+    fn test_from_mat3_round_trip() {
+        let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(Mat2::from_mat3(Mat3::from_mat2(m)), m);
+    }
+
+    #[test]
+    fn test_map() {
+        let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(m.map(|x| x * 2.0), m * 2.0);
+    }
+
+    #[test]
+    fn test_component_mul() {
+        let a = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        let b = Mat2::new(5.0, 6.0, 7.0, 8.0);
+        assert_eq!(a.component_mul(&b), Mat2::new(5.0, 12.0, 21.0, 32.0));
+        assert_ne!(a.component_mul(&b), a * b);
+    }
+
+    #[test]
+    fn test_frobenius_norm() {
+        assert_abs_diff_eq!(Mat2::IDENTITY.frobenius_norm(), 2.0_f64.sqrt(), epsilon = 1e-12);
+        assert_eq!(Mat2::new(1.0, 2.0, 2.0, 4.0).frobenius_norm(), 5.0);
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn test_approx() {
+        use approx::{assert_abs_diff_ne, assert_relative_eq};
+        let a = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        let b = Mat2::new(1.0, 2.0, 3.0, 4.0 + 1e-12);
+        assert_abs_diff_eq!(a, b);
+        assert_relative_eq!(a, b);
+        assert_abs_diff_ne!(a, a * 2.0);
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn test_mint_round_trip() {
+        let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        let column: mint::ColumnMatrix2<f64> = m.into();
+        // The first column of the matrix is (a, c).
+        assert_eq!((column.x.x, column.x.y), (1.0, 3.0));
+        assert_eq!(Mat2::from(column), m);
+    }
+
+    #[test]
+    fn test_mul_by_ref() {
+        let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        let n = Mat2::new(7.0, 2.0, 6.0, 2.0);
+        let v = Vec2::new(1.0, -1.0);
+
+        // Borrow up front, as a caller iterating over references would.
+        let (m_ref, n_ref, v_ref) = (&m, &n, &v);
+        assert_eq!(m_ref * v_ref, m * v);
+        assert_eq!(m_ref * v, m * v);
+        assert_eq!(m_ref * n_ref, m * n);
+        assert_eq!(m_ref * n, m * n);
+        assert_eq!(m * n_ref, m * n);
+    }
+
+    #[test]
+    fn test_rotation() {
+        let m = Mat2::rotation(std::f64::consts::FRAC_PI_2);
+        assert_eq!(m * Vec2::new(1.0, 2.0), Vec2::new(-2.0, 1.0));
+        assert_eq!(Mat2::rotation(0.0), Mat2::IDENTITY);
+    }
+
+    #[test]
+    fn test_rotation_between() {
+        let pairs = [
+            (Vec2::new(1.0, 2.0), Vec2::new(-3.0, 0.5)),
+            (Vec2::new(2.0, 0.0), Vec2::new(-1.0, 0.0)),
+            (Vec2::new(0.0, -1.0), Vec2::new(5.0, 5.0)),
+        ];
+        for (a, b) in pairs {
+            let m = Mat2::rotation_between(a, b);
+            assert_eq!(m * a.normalize(), b.normalize());
+        }
+    }
+
+    #[test]
+    fn test_rotation_between_zero() {
+        assert_eq!(Mat2::rotation_between(Vec2::ZERO, Vec2::UNIT_X), Mat2::IDENTITY);
+        assert_eq!(Mat2::rotation_between(Vec2::UNIT_X, Vec2::ZERO), Mat2::IDENTITY);
+    }
+
+    #[test]
+    fn test_scale_uniform() {
+        assert_eq!(Mat2::scale_uniform(2.0) * Vec2::ONE, Vec2::new(2.0, 2.0));
+        assert_eq!(Mat2::scale_uniform(1.0), Mat2::IDENTITY);
+    }
+
+    #[test]
+    fn test_from_diagonal() {
+        assert_eq!(Mat2::from_diagonal(Vec2::ONE), Mat2::IDENTITY);
+        let d = Vec2::new(-1.0, 2.5);
+        assert_eq!(Mat2::from_diagonal(d).diagonal(), d);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits() {
+        use num_traits::{One, Zero};
+        assert!(Mat2::zero().is_zero());
+        assert_eq!(Mat2::one(), Mat2::IDENTITY);
+        assert!(Mat2::one().is_one());
+    }
+
+    #[test]
+    fn test_div_scalar() {
+        let m = Mat2::new(2.0, -4.0, 6.0, 1.0);
+        assert_eq!(m / 2.0, Mat2::new(1.0, -2.0, 3.0, 0.5));
+        assert_eq!(m / 2.0, m * 0.5);
+    }
+
+    #[test]
+    fn test_snap_small_to_zero() {
+        let m = Mat2::rotation(std::f64::consts::PI) + Mat2::new(0.0, 1e-16, -1e-16, 0.0);
+        let clean = m.snap_small_to_zero(1e-12);
+        assert_eq!([clean.a, clean.b, clean.c, clean.d], [-1.0, 0.0, 0.0, -1.0]);
+    }
+
+    #[test]
+    fn test_add_sub_assign() {
+        let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        let mut acc = m;
+        acc += m;
+        assert_eq!(acc, m + m);
+        acc -= Mat2::IDENTITY;
+        assert_eq!(acc, m + m - Mat2::IDENTITY);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(m.transpose().transpose(), m);
+        assert_eq!((m * Mat2::rotation(0.3)).transpose(), Mat2::rotation(0.3).transpose() * m.transpose());
+    }
+
+    #[test]
+    fn test_svd_reconstructs() {
+        for m in [
+            Mat2::new(4.0, 0.0, 3.0, -5.0),
+            Mat2::new(1.0, 2.0, 3.0, 4.0),
+            Mat2::new(2.0, 1.0, 1.0, 2.0),
+            Mat2::rotation(0.8) * 3.0,
+            Mat2::new(1.0, 2.0, 2.0, 4.0),
+        ] {
+            let (u, s, v) = m.svd();
+            assert_eq!(u * Mat2::from_diagonal(s) * v.transpose(), m);
+            assert_eq!(u * u.transpose(), Mat2::IDENTITY);
+            assert_eq!(v * v.transpose(), Mat2::IDENTITY);
+            assert!(s.x >= s.y && s.y >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_svd_known_values() {
+        // `[[2, 1], [1, 2]]` is symmetric positive definite, so its singular
+        // values are its eigenvalues.
+        let (_, s, _) = Mat2::new(2.0, 1.0, 1.0, 2.0).svd();
+        assert_eq!(s, Vec2::new(3.0, 1.0));
+        // `MᵀM = [[25, -15], [-15, 25]]` has eigenvalues 40 and 10.
+        let (_, s, _) = Mat2::new(4.0, 0.0, 3.0, -5.0).svd();
+        assert_eq!(s, Vec2::new(40f64.sqrt(), 10f64.sqrt()));
+    }
+
+    #[test]
+    fn test_is_identity_and_diagonal() {
+        assert!(Mat2::IDENTITY.is_identity());
+        assert!(Mat2::IDENTITY.is_diagonal());
+
+        let scale = Mat2::from_diagonal(Vec2::new(2.0, -1.0));
+        assert!(!scale.is_identity());
+        assert!(scale.is_diagonal());
+
+        let rotation = Mat2::rotation(0.9);
+        assert!(!rotation.is_identity());
+        assert!(!rotation.is_diagonal());
+        assert!((rotation * Mat2::rotation(-0.9)).is_identity());
+    }
+
+    #[test]
+    fn test_rows_and_cols_arrays() {
+        let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(Mat2::from_rows_array(m.to_rows_array()), m);
+        assert_eq!(Mat2::from_cols_array(m.to_cols_array()), m);
+        assert_eq!(Mat2::from_cols_array(m.to_rows_array()), m.transpose());
+        assert_eq!(m.to_cols_array(), m.transpose().to_rows_array());
+    }
+
+    #[test]
+    fn test_rotation_degrees() {
+        let half_turn = Mat2::rotation(Degrees(180.0));
+        assert_eq!(half_turn, Mat2::rotation(std::f64::consts::PI));
+        assert_eq!(half_turn * Vec2::new(1.0, 2.0), Vec2::new(-1.0, -2.0));
+        assert_eq!(Mat2::rotation(Radians::from(Degrees(-90.0))) * Vec2::UNIT_Y, Vec2::UNIT_X);
+    }
 }
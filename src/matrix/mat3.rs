@@ -8,8 +8,8 @@
 //! for 3D linear transformations.
 
 use std::ops::Mul;
-use derive_more::{Constructor, Add, Sub, Div};
-use crate::Vec3;
+use derive_more::{Add, AddAssign, Sub, SubAssign, Div};
+use crate::{Basis3, Mat2, Point2D, Vec2, Vec3};
 
 /// a 3×3 matrix of `f64` values.
 ///
@@ -17,13 +17,10 @@ use crate::Vec3;
 ///
 /// ```text
 /// | a  b  c |
-/// | c  d  e |
+/// | d  e  f |
 /// | g  h  i |
 /// ```
-///
-
-/// ```
-#[derive(Constructor, Copy, Clone, Debug, Add, Sub, PartialOrd, Div)]
+#[derive(Copy, Clone, Debug, Add, Sub, AddAssign, SubAssign, PartialOrd, Div)]
 pub struct Mat3 {
     /// First row, first column element.
     pub a: f64,
@@ -46,6 +43,20 @@ pub struct Mat3 {
 }
 
 impl Mat3 {
+    /// Creates a matrix from its nine elements, given in **row-major order**.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Vec3};
+    /// let m = Mat3::new(1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0);
+    /// assert_eq!(m.diagonal(), Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    // One argument per element reads better here than an array.
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64, g: f64, h: f64, i: f64) -> Mat3 {
+        Mat3 { a, b, c, d, e, f, g, h, i }
+    }
+
     /// The **identity matrix**:
     ///
     /// ```text
@@ -82,6 +93,376 @@ impl Mat3 {
         h: 0.0,
         i: 0.0,
     };
+    /// Embeds a 2×2 linear transform in the upper-left of a 3×3 matrix, with the
+    /// rest of the matrix taken from the identity:
+    ///
+    /// ```text
+    /// | a  b  0 |
+    /// | c  d  0 |
+    /// | 0  0  1 |
+    /// ```
+    ///
+    /// In homogeneous 2D coordinates this is the same transform with no translation.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat2, Mat3};
+    /// assert_eq!(Mat3::from_mat2(Mat2::IDENTITY), Mat3::IDENTITY);
+    /// ```
+    pub fn from_mat2(m: Mat2) -> Mat3 {
+        Mat3::new(
+            m.a, m.b, 0.0,
+            m.c, m.d, 0.0,
+            0.0, 0.0, 1.0,
+        )
+    }
+
+    /// Returns the matrix that **scales** every axis by the same factor `s`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Vec3};
+    /// assert_eq!(Mat3::scale_uniform(2.0) * Vec3::ONE, Vec3::new(2.0, 2.0, 2.0));
+    /// ```
+    pub fn scale_uniform(s: f64) -> Mat3 {
+        Mat3::new(
+            s, 0.0, 0.0,
+            0.0, s, 0.0,
+            0.0, 0.0, s,
+        )
+    }
+
+    /// Returns the matrix with `d` on its **diagonal** and zeros elsewhere.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Vec3};
+    /// let m = Mat3::from_diagonal(Vec3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(m * Vec3::ONE, Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn from_diagonal(d: Vec3) -> Mat3 {
+        Mat3::new(
+            d.x, 0.0, 0.0,
+            0.0, d.y, 0.0,
+            0.0, 0.0, d.z,
+        )
+    }
+
+    /// Returns the **diagonal** elements of the matrix, `(a, e, i)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Vec3};
+    /// let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(m.diagonal(), Vec3::new(1.0, 5.0, 9.0));
+    /// ```
+    pub fn diagonal(&self) -> Vec3 {
+        Vec3::new(self.a, self.e, self.i)
+    }
+
+    /// Returns the matrix that **rotates** anticlockwise by `radians` about `axis`,
+    /// using Rodrigues' rotation formula:
+    /// \[
+    /// R = \cos\theta \, I + \sin\theta \, \[k\]_\times + (1 - \cos\theta) \, k k^T
+    /// \]
+    /// where `k` is the normalized axis.
+    ///
+    /// `axis` must be non-zero: a zero axis can't be normalized, and every
+    /// element of the result is NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Vec3};
+    /// let m = Mat3::from_axis_angle(Vec3::UNIT_Z, std::f64::consts::FRAC_PI_2);
+    /// assert_eq!(m * Vec3::UNIT_X, Vec3::UNIT_Y);
+    /// ```
+    pub fn from_axis_angle(axis: Vec3, radians: f64) -> Mat3 {
+        let k = axis.normalize();
+        let (sin, cos) = radians.sin_cos();
+        let cross = Mat3::new(
+            0.0, -k.z, k.y,
+            k.z, 0.0, -k.x,
+            -k.y, k.x, 0.0,
+        );
+        cos * Mat3::IDENTITY + sin * cross + (1.0 - cos) * k.outer(&k)
+    }
+
+    /// Returns the shortest-arc rotation that turns the direction of `from` onto
+    /// the direction of `to`.
+    ///
+    /// The rotation axis is `from × to`. When the vectors point the same way the
+    /// identity is returned; when they point in opposite directions any
+    /// perpendicular axis works, so one is picked with [`Vec3::orthonormal_basis`].
+    /// The identity is also returned if either vector has zero length.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Vec3};
+    /// let m = Mat3::rotation_between(Vec3::UNIT_X, Vec3::UNIT_Y);
+    /// assert_eq!(m * Vec3::UNIT_X, Vec3::UNIT_Y);
+    /// ```
+    pub fn rotation_between(from: Vec3, to: Vec3) -> Mat3 {
+        let (Some(from), Some(to)) = (from.try_normalize(), to.try_normalize()) else {
+            return Mat3::IDENTITY;
+        };
+
+        let axis = from.cross(&to);
+        let sin = axis.mag();
+        let cos = from.dot(&to);
+        if sin < EPSILON {
+            if cos > 0.0 {
+                return Mat3::IDENTITY;
+            }
+            let (perpendicular, _) = from.orthonormal_basis();
+            return Mat3::from_axis_angle(perpendicular, std::f64::consts::PI);
+        }
+
+        Mat3::from_axis_angle(axis, sin.atan2(cos))
+    }
+
+    /// Returns the **change-of-basis** matrix that carries each axis of `from`
+    /// onto the matching axis of `to`.
+    ///
+    /// With each frame written as a matrix whose columns are its axes (see
+    /// [`Basis3::to_mat3`]), this is `To * Fromᵀ`. Both frames must be
+    /// orthonormal, so that the transpose of `From` is its inverse; if they
+    /// have the same handedness the result is a rotation.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Basis3, Mat3, Vec3};
+    /// let y_up = Basis3::new(Vec3::UNIT_X, -Vec3::UNIT_Z, Vec3::UNIT_Y);
+    /// let z_up = Basis3::new(Vec3::UNIT_X, Vec3::UNIT_Y, Vec3::UNIT_Z);
+    /// let m = Mat3::change_of_basis(&y_up, &z_up);
+    /// assert_eq!(m * Vec3::UNIT_Y, Vec3::UNIT_Z);
+    /// ```
+    pub fn change_of_basis(from: &Basis3, to: &Basis3) -> Mat3 {
+        to.to_mat3() * from.to_mat3().transpose()
+    }
+
+    /// Returns the 2D homogeneous matrix that **translates** points by `t`:
+    ///
+    /// ```text
+    /// | 1  0  tx |
+    /// | 0  1  ty |
+    /// | 0  0  1  |
+    /// ```
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Point2D, Vec2};
+    /// let m = Mat3::translation_2d(Vec2::new(2.0, 3.0));
+    /// assert_eq!(m.transform_point_2d(Point2D::ONE), Point2D::new(3.0, 4.0));
+    /// ```
+    pub fn translation_2d(t: Vec2) -> Mat3 {
+        Mat3::new(
+            1.0, 0.0, t.x,
+            0.0, 1.0, t.y,
+            0.0, 0.0, 1.0,
+        )
+    }
+
+    /// Returns the 2D homogeneous matrix that **rotates** anticlockwise by `radians`
+    /// about the origin.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Point2D};
+    /// let m = Mat3::rotation_2d(std::f64::consts::PI);
+    /// assert_eq!(m.transform_point_2d(Point2D::new(1.0, 2.0)), Point2D::new(-1.0, -2.0));
+    /// ```
+    pub fn rotation_2d(radians: f64) -> Mat3 {
+        Mat3::from_mat2(Mat2::rotation(radians))
+    }
+
+    /// Returns the 2D homogeneous matrix that **scales** each axis by the matching
+    /// component of `s`, about the origin.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Point2D, Vec2};
+    /// let m = Mat3::scale_2d(Vec2::new(2.0, 3.0));
+    /// assert_eq!(m.transform_point_2d(Point2D::ONE), Point2D::new(2.0, 3.0));
+    /// ```
+    pub fn scale_2d(s: Vec2) -> Mat3 {
+        Mat3::from_mat2(Mat2::new(s.x, 0.0, 0.0, s.y))
+    }
+
+    /// Returns the 2D homogeneous matrix that scales by `scale`, rotates
+    /// anticlockwise by `rotation` radians, then translates by `translation`.
+    ///
+    /// This is `T * R * S` built in one step:
+    ///
+    /// ```text
+    /// | sx·cos θ  -sy·sin θ  tx |
+    /// | sx·sin θ   sy·cos θ  ty |
+    /// | 0          0         1  |
+    /// ```
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Point2D, Vec2};
+    /// let m = Mat3::from_trs_2d(Vec2::new(1.0, 0.0), 0.0, Vec2::new(2.0, 2.0));
+    /// assert_eq!(m.transform_point_2d(Point2D::ONE), Point2D::new(3.0, 2.0));
+    /// ```
+    pub fn from_trs_2d(translation: Vec2, rotation: f64, scale: Vec2) -> Mat3 {
+        let (sin, cos) = rotation.sin_cos();
+        Mat3::new(
+            scale.x * cos, -scale.y * sin, translation.x,
+            scale.x * sin, scale.y * cos, translation.y,
+            0.0, 0.0, 1.0,
+        )
+    }
+
+    /// Returns the 2D homogeneous matrix that **scales** by `scale` about
+    /// `pivot` instead of the origin, e.g. to zoom a view around the cursor.
+    ///
+    /// This is `T(pivot) * S(scale) * T(-pivot)`, so `pivot` stays where it is.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Point2D, Vec2};
+    /// let m = Mat3::scale_about_point(Vec2::new(1.0, 1.0), Vec2::new(2.0, 2.0));
+    /// assert_eq!(m.transform_point_2d(Point2D::new(1.0, 1.0)), Point2D::new(1.0, 1.0));
+    /// assert_eq!(m.transform_point_2d(Point2D::new(2.0, 1.0)), Point2D::new(3.0, 1.0));
+    /// ```
+    pub fn scale_about_point(pivot: Vec2, scale: Vec2) -> Mat3 {
+        Mat3::translation_2d(pivot) * Mat3::scale_2d(scale) * Mat3::translation_2d(-pivot)
+    }
+
+    /// Returns the 2D homogeneous matrix that **rotates** anticlockwise by
+    /// `radians` about `pivot` instead of the origin.
+    ///
+    /// This is `T(pivot) * R(radians) * T(-pivot)`, so `pivot` stays where it is.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Point2D, Vec2};
+    /// let m = Mat3::rotate_about_point(Vec2::new(1.0, 0.0), std::f64::consts::PI);
+    /// assert_eq!(m.transform_point_2d(Point2D::ZERO), Point2D::new(2.0, 0.0));
+    /// ```
+    pub fn rotate_about_point(pivot: Vec2, radians: f64) -> Mat3 {
+        Mat3::translation_2d(pivot) * Mat3::rotation_2d(radians) * Mat3::translation_2d(-pivot)
+    }
+
+    /// Returns the 2D **orthographic projection** that maps the rectangle
+    /// `[left, right] × [bottom, top]` onto `[-1, 1] × [-1, 1]`, e.g. for a 2D
+    /// camera producing normalized device coordinates:
+    ///
+    /// ```text
+    /// | 2/(r-l)  0        -(r+l)/(r-l) |
+    /// | 0        2/(t-b)  -(t+b)/(t-b) |
+    /// | 0        0         1           |
+    /// ```
+    ///
+    /// Swapping `bottom` and `top` flips the `y` axis, as is common for screen
+    /// coordinates that grow downwards.
+    ///
+    /// # Panics
+    /// Panics if `left == right` or `bottom == top`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Point2D};
+    /// let m = Mat3::ortho_2d(0.0, 800.0, 0.0, 600.0);
+    /// assert_eq!(m.transform_point_2d(Point2D::new(400.0, 300.0)), Point2D::ZERO);
+    /// assert_eq!(m.transform_point_2d(Point2D::new(800.0, 600.0)), Point2D::ONE);
+    /// ```
+    pub fn ortho_2d(left: f64, right: f64, bottom: f64, top: f64) -> Mat3 {
+        if left == right || bottom == top {
+            panic!("Orthographic rectangle has zero width or height.");
+        }
+        let (w, h) = (right - left, top - bottom);
+        Mat3::new(
+            2.0 / w, 0.0, -(right + left) / w,
+            0.0, 2.0 / h, -(top + bottom) / h,
+            0.0, 0.0, 1.0,
+        )
+    }
+
+    /// Applies a 2D homogeneous transform to a **point**, including translation.
+    ///
+    /// The point is treated as `(x, y, 1)`. The matrix is assumed to be affine
+    /// (bottom row `0 0 1`), so no perspective divide is done.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Point2D, Vec2};
+    /// let m = Mat3::translation_2d(Vec2::new(1.0, 0.0));
+    /// assert_eq!(m.transform_point_2d(Point2D::ZERO), Point2D::new(1.0, 0.0));
+    /// ```
+    pub fn transform_point_2d(&self, p: Point2D) -> Point2D {
+        let v = *self * p.extend(1.0);
+        Point2D::new(v.x, v.y)
+    }
+
+    /// Applies a 2D homogeneous transform to a **direction**, ignoring translation.
+    ///
+    /// The vector is treated as `(x, y, 0)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Vec2};
+    /// let m = Mat3::translation_2d(Vec2::new(1.0, 0.0));
+    /// assert_eq!(m.transform_vector_2d(Vec2::UNIT_Y), Vec2::UNIT_Y);
+    /// ```
+    pub fn transform_vector_2d(&self, v: Vec2) -> Vec2 {
+        let v = *self * v.extend(0.0);
+        Vec2::new(v.x, v.y)
+    }
+
+    /// Applies a function `f` to each element of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(m.map(|x| x * x), Mat3::new(1.0, 4.0, 9.0, 16.0, 25.0, 36.0, 49.0, 64.0, 81.0));
+    /// ```
+    pub fn map<F>(&self, f: F) -> Mat3
+    where
+        F: Fn(f64) -> f64,
+    {
+        Mat3::new(
+            f(self.a), f(self.b), f(self.c),
+            f(self.d), f(self.e), f(self.f),
+            f(self.g), f(self.h), f(self.i),
+        )
+    }
+
+    /// Returns the **element-wise** (Hadamard) product of `self` and `other`.
+    ///
+    /// This is **not** matrix multiplication, use `*` for that.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(m.component_mul(&Mat3::IDENTITY), Mat3::new(1.0, 0.0, 0.0, 0.0, 5.0, 0.0, 0.0, 0.0, 9.0));
+    /// ```
+    pub fn component_mul(&self, other: &Mat3) -> Mat3 {
+        Mat3::new(
+            self.a * other.a, self.b * other.b, self.c * other.c,
+            self.d * other.d, self.e * other.e, self.f * other.f,
+            self.g * other.g, self.h * other.h, self.i * other.i,
+        )
+    }
+
+    /// Replaces every element with an absolute value below `eps` by exactly
+    /// `0.0`, cleaning up rounding noise such as `1e-17` left behind by a chain
+    /// of multiplications.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Vec3};
+    /// let m = Mat3::from_axis_angle(Vec3::UNIT_Z, std::f64::consts::FRAC_PI_2);
+    /// assert_ne!(m.a, 0.0);
+    /// assert_eq!(m.snap_small_to_zero(1e-12).a, 0.0);
+    /// ```
+    pub fn snap_small_to_zero(&self, eps: f64) -> Mat3 {
+        self.map(|x| if x.abs() < eps { 0.0 } else { x })
+    }
+
     /// Returns the **determinant** of the matrix.
     ///
     /// Computed as:
@@ -135,78 +516,678 @@ impl Mat3 {
         }
     }
 
-}
+    /// Returns the **transpose** of the matrix, swapping rows and columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(m.transpose(), Mat3::new(1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0));
+    /// ```
+    pub fn transpose(&self) -> Mat3 {
+        Mat3::new(
+            self.a, self.d, self.g,
+            self.b, self.e, self.h,
+            self.c, self.f, self.i,
+        )
+    }
 
-const EPSILON: f64 = 1e-9;
+    /// Returns `true` if the matrix is the identity (within epsilon), e.g. to
+    /// skip applying a transform that does nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// assert!(Mat3::IDENTITY.is_identity());
+    /// assert!(!Mat3::scale_uniform(2.0).is_identity());
+    /// ```
+    pub fn is_identity(&self) -> bool {
+        *self == Mat3::IDENTITY
+    }
 
-impl PartialEq for Mat3 {
-    fn eq(&self, other: &Self) -> bool {
-        (self.a - other.a).abs() < EPSILON &&
-            (self.b - other.b).abs() < EPSILON &&
-            (self.c - other.c).abs() < EPSILON &&
-            (self.d - other.d).abs() < EPSILON &&
-            (self.e - other.e).abs() < EPSILON &&
-            (self.f - other.f).abs() < EPSILON &&
-            (self.g - other.g).abs() < EPSILON &&
-            (self.h - other.h).abs() < EPSILON &&
-            (self.i - other.i).abs() < EPSILON
+    /// Returns `true` if every element off the diagonal is zero (within
+    /// epsilon), as for a pure axis-aligned scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Vec3};
+    /// assert!(Mat3::from_diagonal(Vec3::new(1.0, 2.0, 3.0)).is_diagonal());
+    /// assert!(!Mat3::rotation_2d(0.5).is_diagonal());
+    /// ```
+    pub fn is_diagonal(&self) -> bool {
+        [self.b, self.c, self.d, self.f, self.g, self.h]
+            .iter()
+            .all(|x| x.abs() < EPSILON)
     }
-}
 
-impl Mul<Vec3> for Mat3 {
-    type Output = Vec3;
+    /// Returns `true` if the matrix equals its own transpose (within epsilon).
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let m = Mat3::new(1.0, 2.0, 3.0, 2.0, 4.0, 5.0, 3.0, 5.0, 6.0);
+    /// assert!(m.is_symmetric());
+    /// ```
+    pub fn is_symmetric(&self) -> bool {
+        *self == self.transpose()
+    }
 
-    fn mul(self, rhs: Vec3) -> Vec3 {
-        Vec3 {
-            x: self.a * rhs.x + self.b * rhs.y + self.c * rhs.z,
-            y: self.d * rhs.x + self.e * rhs.y + self.f * rhs.z,
-            z: self.g * rhs.x + self.h * rhs.y + self.i * rhs.z,
-        }
+    /// Returns the **symmetric part** of the matrix, `(M + Mᵀ) / 2`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let m = Mat3::new(1.0, 2.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(m.symmetric_part(), Mat3::new(1.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub fn symmetric_part(&self) -> Mat3 {
+        0.5 * (*self + self.transpose())
     }
-}
 
-impl Mul<Mat3> for Mat3 {
-    type Output = Mat3;
+    /// Returns the **antisymmetric** (skew-symmetric) part of the matrix, `(M - Mᵀ) / 2`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let m = Mat3::new(1.0, 2.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(m.antisymmetric_part(), Mat3::new(0.0, 1.0, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0));
+    /// ```
+    pub fn antisymmetric_part(&self) -> Mat3 {
+        0.5 * (*self - self.transpose())
+    }
 
-    fn mul(self, rhs: Mat3) -> Mat3 {
-        Mat3 {
-            a: self.a * rhs.a + self.b * rhs.d + self.c * rhs.g,
-            b: self.a * rhs.b + self.b * rhs.e + self.c * rhs.h,
-            c: self.a * rhs.c + self.b * rhs.f + self.c * rhs.i,
-            d: self.d * rhs.a + self.e * rhs.d + self.f * rhs.g,
-            e: self.d * rhs.b + self.e * rhs.e + self.f * rhs.h,
-            f: self.d * rhs.c + self.e * rhs.f + self.f * rhs.i,
-            g: self.g * rhs.a + self.h * rhs.d + self.i * rhs.g,
-            h: self.g * rhs.b + self.h * rhs.e + self.i * rhs.h,
-            i: self.g * rhs.c + self.h * rhs.f + self.i * rhs.i,
-        }
+    /// Returns the **LU decomposition** of the matrix using partial pivoting.
+    ///
+    /// The result is `(L, U, P)` where `L` is unit lower-triangular, `U` is
+    /// upper-triangular and `P` is the row permutation, such that row `i` of
+    /// `L * U` is row `P[i]` of the original matrix.
+    ///
+    /// Returns `None` if the matrix is singular, meaning a pivot is smaller than
+    /// epsilon times the largest absolute element, so the test doesn't depend
+    /// on the overall scale of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let m = Mat3::new(1.0, 2.0, 3.0, 3.0, 2.0, 1.0, 2.0, 1.0, 3.0);
+    /// let (l, u, p) = m.lu_decompose().unwrap();
+    /// assert_eq!(p, [1, 0, 2]);
+    /// assert_eq!((l * u).d, m.a);
+    /// ```
+    pub fn lu_decompose(&self) -> Option<(Mat3, Mat3, [usize; 3])> {
+        let largest = self
+            .to_rows_array()
+            .iter()
+            .flatten()
+            .fold(0.0, |m: f64, x| m.max(x.abs()));
+        self.lu_with_tolerance(EPSILON * largest)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// LU decomposition with partial pivoting, failing if a pivot's magnitude
+    /// is no more than `tolerance`.
+    fn lu_with_tolerance(&self, tolerance: f64) -> Option<(Mat3, Mat3, [usize; 3])> {
+        let mut u = self.to_rows_array();
+        let mut l = [[0.0; 3]; 3];
+        let mut perm = [0, 1, 2];
 
-    #[test]
-    fn test_add() {
-        let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
-        assert_eq!(m + m, Mat3::new(2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0, 18.0));
-    }
+        for col in 0..3 {
+            // Swap the row with the largest pivot into place to keep the factors stable.
+            let pivot = (col..3)
+                .max_by(|&x, &y| u[x][col].abs().total_cmp(&u[y][col].abs()))
+                .unwrap();
+            if u[pivot][col].abs() <= tolerance {
+                return None;
+            }
+            u.swap(col, pivot);
+            l.swap(col, pivot);
+            perm.swap(col, pivot);
 
-    #[test]
-    fn sub() {
-        let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
-        assert_eq!(m - m, Mat3::ZERO);
-    }
+            l[col][col] = 1.0;
+            let pivot_row = u[col];
+            for row in col + 1..3 {
+                let factor = u[row][col] / pivot_row[col];
+                l[row][col] = factor;
+                for (x, p) in u[row].iter_mut().zip(pivot_row).skip(col) {
+                    *x -= factor * p;
+                }
+            }
+        }
 
-    #[test]
-    fn test_mat_mul() {
-        let a = Mat3::IDENTITY;
-        let b = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
-        assert_eq!(a * b, b);
+        Some((Mat3::from_rows_array(l), Mat3::from_rows_array(u), perm))
     }
 
-    #[test]
+    /// Returns the **determinant** of the matrix, computed from its
+    /// [LU decomposition](Mat3::lu_decompose) as the product of the diagonal of
+    /// `U`, negated if the row permutation is odd.
+    ///
+    /// For well-conditioned matrices this matches
+    /// [`determinant`](Mat3::determinant). It differs when the cofactor
+    /// expansion has to subtract large, nearly equal products, e.g. for a matrix
+    /// with big entries whose rows are almost dependent. There the cofactors
+    /// cancel catastrophically, while pivoting keeps the elimination exact or
+    /// close to it.
+    ///
+    /// Unlike [`lu_decompose`](Mat3::lu_decompose), no pivot tolerance is
+    /// applied: `0.0` is only returned when a pivot is exactly zero, so tiny but
+    /// non-singular matrices keep their determinant.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let m = Mat3::new(
+    ///     1e8, 1e8, 1e8,
+    ///     1e8, 1e8 + 1.0, 1e8,
+    ///     1e8, 1e8, 1e8 + 1.0,
+    /// );
+    /// assert_eq!(m.determinant(), 0.0);
+    /// assert_eq!(m.determinant_stable(), 1e8);
+    /// ```
+    pub fn determinant_stable(&self) -> f64 {
+        let Some((_, u, perm)) = self.lu_with_tolerance(0.0) else {
+            return 0.0;
+        };
+        let inversions = (0..3)
+            .flat_map(|i| (i + 1..3).map(move |j| (i, j)))
+            .filter(|&(i, j)| perm[i] > perm[j])
+            .count();
+        let sign = if inversions % 2 == 0 { 1.0 } else { -1.0 };
+
+        sign * u.a * u.e * u.i
+    }
+
+    /// Returns the **Cholesky decomposition** of the matrix: the
+    /// lower-triangular `L` with a positive diagonal such that `M = L * Lᵀ`.
+    ///
+    /// The elements of `L` are solved for one at a time, column by column:
+    /// \[
+    /// L_{jj} = \sqrt{M_{jj} - \sum_{k<j} L_{jk}^2}, \quad
+    /// L_{ij} = \frac{M_{ij} - \sum_{k<j} L_{ik} L_{jk}}{L_{jj}}
+    /// \]
+    /// For symmetric positive-definite systems, such as least-squares normal
+    /// equations, this takes about half the work of an LU decomposition and
+    /// needs no pivoting.
+    ///
+    /// Returns `None` if the matrix isn't symmetric positive-definite. The
+    /// symmetry test is relative to the
+    /// [Frobenius norm](Mat3::frobenius_norm), so large matrices whose
+    /// off-diagonals differ only by rounding are still accepted.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let m = Mat3::new(4.0, 2.0, 0.0, 2.0, 5.0, 0.0, 0.0, 0.0, 9.0);
+    /// let l = m.cholesky().unwrap();
+    /// assert_eq!(l, Mat3::new(2.0, 0.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 3.0));
+    /// assert_eq!(l * l.transpose(), m);
+    /// ```
+    pub fn cholesky(&self) -> Option<Mat3> {
+        let asymmetry = (*self - self.transpose()).frobenius_norm();
+        if asymmetry > EPSILON * self.frobenius_norm() {
+            return None;
+        }
+
+        let m = self.to_rows_array();
+        let mut l = [[0.0; 3]; 3];
+        for j in 0..3 {
+            let diag = m[j][j] - (0..j).map(|k| l[j][k] * l[j][k]).sum::<f64>();
+            if diag <= 0.0 {
+                return None;
+            }
+            l[j][j] = diag.sqrt();
+
+            for i in j + 1..3 {
+                let dot: f64 = (0..j).map(|k| l[i][k] * l[j][k]).sum();
+                l[i][j] = (m[i][j] - dot) / l[j][j];
+            }
+        }
+
+        Some(Mat3::from_rows_array(l))
+    }
+
+    /// Returns the **eigenvalues** and **eigenvectors** of a symmetric matrix,
+    /// found with the cyclic Jacobi method.
+    ///
+    /// Each Jacobi rotation zeroes one off-diagonal element; sweeping over all
+    /// three repeatedly drives the matrix to diagonal form `D = Vᵀ M V`. The
+    /// result is `(λ, V)`, with the eigenvalues largest first and column `k` of
+    /// `V` the unit eigenvector for `λ[k]`. `V` is orthogonal, so
+    /// `M = V diag(λ) Vᵀ`.
+    ///
+    /// The matrix is assumed to be **symmetric**, e.g. a covariance matrix. Only
+    /// the symmetric part of a non-symmetric matrix is meaningful here.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Vec3};
+    /// let m = Mat3::new(2.0, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 5.0);
+    /// let (values, vectors) = m.symmetric_eigen();
+    /// assert_eq!(values, Vec3::new(5.0, 3.0, 1.0));
+    /// assert_eq!(m * vectors, vectors * Mat3::from_diagonal(values));
+    /// ```
+    pub fn symmetric_eigen(&self) -> (Vec3, Mat3) {
+        const MAX_SWEEPS: usize = 50;
+        const PAIRS: [(usize, usize); 3] = [(0, 1), (0, 2), (1, 2)];
+
+        let mut m = self.to_rows_array();
+        let mut v = Mat3::IDENTITY.to_rows_array();
+        // Rotations preserve the Frobenius norm, so one threshold serves every sweep.
+        let tolerance = EPSILON * EPSILON * self.frobenius_norm().powi(2);
+
+        for _ in 0..MAX_SWEEPS {
+            let off: f64 = PAIRS.iter().map(|&(p, q)| m[p][q] * m[p][q]).sum();
+            if off <= tolerance {
+                break;
+            }
+
+            for (p, q) in PAIRS {
+                if m[p][q] == 0.0 {
+                    continue;
+                }
+                // Choose the smaller rotation angle `t = tan θ` that zeroes `m[p][q]`.
+                let theta = (m[q][q] - m[p][p]) / (2.0 * m[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                // M ← Jᵀ M J, then V ← V J.
+                for row in m.iter_mut().chain(v.iter_mut()) {
+                    let (rp, rq) = (row[p], row[q]);
+                    row[p] = c * rp - s * rq;
+                    row[q] = s * rp + c * rq;
+                }
+                let (mp, mq) = (m[p], m[q]);
+                for (k, (xp, xq)) in mp.iter().zip(mq).enumerate() {
+                    m[p][k] = c * xp - s * xq;
+                    m[q][k] = s * xp + c * xq;
+                }
+            }
+        }
+
+        let mut order = [0, 1, 2];
+        order.sort_by(|&x, &y| m[y][y].total_cmp(&m[x][x]));
+        let values = Vec3::new(m[order[0]][order[0]], m[order[1]][order[1]], m[order[2]][order[2]]);
+        let vectors = Mat3::from_rows_array(v.map(|row| order.map(|k| row[k])));
+
+        (values, vectors)
+    }
+
+    /// Returns the **Frobenius norm** of the matrix, the square root of the sum
+    /// of its squared elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let m = Mat3::new(1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0);
+    /// assert_eq!(m.frobenius_norm(), 3.0);
+    /// ```
+    pub fn frobenius_norm(&self) -> f64 {
+        self.to_rows_array()
+            .iter()
+            .flatten()
+            .map(|x| x * x)
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Returns `true` if the matrix is **orthogonal**, meaning `M * Mᵀ = I` (within epsilon).
+    ///
+    /// The rows (and columns) of an orthogonal matrix are unit length and
+    /// mutually perpendicular.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let swap = Mat3::new(0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+    /// assert!(swap.is_orthogonal());
+    /// ```
+    pub fn is_orthogonal(&self) -> bool {
+        *self * self.transpose() == Mat3::IDENTITY
+    }
+
+    /// Returns `true` if the matrix preserves orientation (its determinant is positive).
+    ///
+    /// A matrix with a negative determinant includes a reflection.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let mirror = Mat3::new(-1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+    /// assert!(Mat3::IDENTITY.is_right_handed());
+    /// assert!(!mirror.is_right_handed());
+    /// ```
+    pub fn is_right_handed(&self) -> bool {
+        self.determinant() > 0.0
+    }
+
+    /// Returns `true` if the matrix is a pure **rotation**: orthogonal with a
+    /// determinant of `1` (within epsilon).
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let quarter_turn = Mat3::new(0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+    /// assert!(quarter_turn.is_rotation());
+    /// ```
+    pub fn is_rotation(&self) -> bool {
+        self.is_orthogonal() && (self.determinant() - 1.0).abs() < EPSILON
+    }
+
+    /// Builds a matrix from an array of **rows**, so `rows[r][c]` becomes the
+    /// element in row `r`, column `c`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let m = Mat3::from_rows_array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    /// assert_eq!(m, Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0));
+    /// ```
+    pub fn from_rows_array(rows: [[f64; 3]; 3]) -> Mat3 {
+        let [[a, b, c], [d, e, f], [g, h, i]] = rows;
+        Mat3::new(a, b, c, d, e, f, g, h, i)
+    }
+
+    /// Returns the elements of the matrix as an array of **rows**.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(m.to_rows_array()[1], [4.0, 5.0, 6.0]);
+    /// ```
+    pub fn to_rows_array(&self) -> [[f64; 3]; 3] {
+        [
+            [self.a, self.b, self.c],
+            [self.d, self.e, self.f],
+            [self.g, self.h, self.i],
+        ]
+    }
+
+    /// Builds a matrix from an array of **columns**, so `cols[c][r]` becomes the
+    /// element in row `r`, column `c`.
+    ///
+    /// This is the layout used by column-major libraries such as OpenGL.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let m = Mat3::from_cols_array([[1.0, 4.0, 7.0], [2.0, 5.0, 8.0], [3.0, 6.0, 9.0]]);
+    /// assert_eq!(m, Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0));
+    /// ```
+    pub fn from_cols_array(cols: [[f64; 3]; 3]) -> Mat3 {
+        Mat3::from_rows_array(cols).transpose()
+    }
+
+    /// Returns the elements of the matrix as an array of **columns**.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Mat3;
+    /// let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(m.to_cols_array()[1], [2.0, 5.0, 8.0]);
+    /// ```
+    pub fn to_cols_array(&self) -> [[f64; 3]; 3] {
+        self.transpose().to_rows_array()
+    }
+}
+
+const EPSILON: f64 = 1e-9;
+
+impl PartialEq for Mat3 {
+    fn eq(&self, other: &Self) -> bool {
+        (self.a - other.a).abs() < EPSILON &&
+            (self.b - other.b).abs() < EPSILON &&
+            (self.c - other.c).abs() < EPSILON &&
+            (self.d - other.d).abs() < EPSILON &&
+            (self.e - other.e).abs() < EPSILON &&
+            (self.f - other.f).abs() < EPSILON &&
+            (self.g - other.g).abs() < EPSILON &&
+            (self.h - other.h).abs() < EPSILON &&
+            (self.i - other.i).abs() < EPSILON
+    }
+}
+
+/// Implements **matrix–scalar multiplication** (`Mat3 * f64`).
+///
+/// Each element of the matrix is scaled by the scalar. Division by a scalar
+/// (`Mat3 / f64`) is derived and divides each element the same way. There is
+/// deliberately no `f64 / Mat3`; use [`inverse`](Mat3::inverse) for that.
+///
+/// # Examples
+/// ```
+/// use lars::Mat3;
+/// let m = Mat3::IDENTITY;
+/// assert_eq!(m * 2.0, Mat3::new(2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0));
+/// assert_eq!(m / 2.0, Mat3::new(0.5, 0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.5));
+/// ```
+impl Mul<f64> for Mat3 {
+    type Output = Mat3;
+    fn mul(self, s: f64) -> Mat3 {
+        Mat3::new(
+            self.a * s, self.b * s, self.c * s,
+            self.d * s, self.e * s, self.f * s,
+            self.g * s, self.h * s, self.i * s,
+        )
+    }
+}
+
+/// Implements **scalar–matrix multiplication** (`f64 * Mat3`).
+///
+/// # Examples
+/// ```
+/// use lars::Mat3;
+/// let m = Mat3::IDENTITY;
+/// assert_eq!(2.0 * m, m * 2.0);
+/// ```
+impl Mul<Mat3> for f64 {
+    type Output = Mat3;
+    fn mul(self, m: Mat3) -> Mat3 {
+        m * self
+    }
+}
+
+impl Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        Vec3 {
+            x: self.a * rhs.x + self.b * rhs.y + self.c * rhs.z,
+            y: self.d * rhs.x + self.e * rhs.y + self.f * rhs.z,
+            z: self.g * rhs.x + self.h * rhs.y + self.i * rhs.z,
+        }
+    }
+}
+
+impl Mul<Mat3> for Mat3 {
+    type Output = Mat3;
+
+    fn mul(self, rhs: Mat3) -> Mat3 {
+        Mat3 {
+            a: self.a * rhs.a + self.b * rhs.d + self.c * rhs.g,
+            b: self.a * rhs.b + self.b * rhs.e + self.c * rhs.h,
+            c: self.a * rhs.c + self.b * rhs.f + self.c * rhs.i,
+            d: self.d * rhs.a + self.e * rhs.d + self.f * rhs.g,
+            e: self.d * rhs.b + self.e * rhs.e + self.f * rhs.h,
+            f: self.d * rhs.c + self.e * rhs.f + self.f * rhs.i,
+            g: self.g * rhs.a + self.h * rhs.d + self.i * rhs.g,
+            h: self.g * rhs.b + self.h * rhs.e + self.i * rhs.h,
+            i: self.g * rhs.c + self.h * rhs.f + self.i * rhs.i,
+        }
+    }
+}
+
+/// Implements **matrix–vector multiplication** by reference (`&Mat3 * &Vec3`).
+///
+/// # Examples
+/// ```
+/// use lars::{Mat3, Vec3};
+/// let m = Mat3::IDENTITY;
+/// let v = Vec3::ONE;
+/// assert_eq!(&m * &v, v);
+/// ```
+impl Mul<&Vec3> for &Mat3 {
+    type Output = Vec3;
+    fn mul(self, v: &Vec3) -> Vec3 {
+        *self * *v
+    }
+}
+
+/// Implements **matrix–vector multiplication** with a borrowed matrix (`&Mat3 * Vec3`).
+impl Mul<Vec3> for &Mat3 {
+    type Output = Vec3;
+    fn mul(self, v: Vec3) -> Vec3 {
+        *self * v
+    }
+}
+
+/// Implements **matrix–matrix multiplication** by reference (`&Mat3 * &Mat3`).
+///
+/// # Examples
+/// ```
+/// use lars::Mat3;
+/// let a = Mat3::IDENTITY;
+/// let b = Mat3::IDENTITY * 2.0;
+/// assert_eq!(&a * &b, b);
+/// ```
+impl Mul<&Mat3> for &Mat3 {
+    type Output = Mat3;
+    fn mul(self, m: &Mat3) -> Mat3 {
+        *self * *m
+    }
+}
+
+/// Implements **matrix–matrix multiplication** with a borrowed left-hand side (`&Mat3 * Mat3`).
+impl Mul<Mat3> for &Mat3 {
+    type Output = Mat3;
+    fn mul(self, m: Mat3) -> Mat3 {
+        *self * m
+    }
+}
+
+/// Implements **matrix–matrix multiplication** with a borrowed right-hand side (`Mat3 * &Mat3`).
+impl Mul<&Mat3> for Mat3 {
+    type Output = Mat3;
+    fn mul(self, m: &Mat3) -> Mat3 {
+        self * *m
+    }
+}
+
+/// Compares two [`Mat3`]s element by element, within an absolute tolerance.
+///
+/// Available with the `approx` feature.
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Mat3 {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        EPSILON
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.a.abs_diff_eq(&other.a, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+            && self.c.abs_diff_eq(&other.c, epsilon)
+            && self.d.abs_diff_eq(&other.d, epsilon)
+            && self.e.abs_diff_eq(&other.e, epsilon)
+            && self.f.abs_diff_eq(&other.f, epsilon)
+            && self.g.abs_diff_eq(&other.g, epsilon)
+            && self.h.abs_diff_eq(&other.h, epsilon)
+            && self.i.abs_diff_eq(&other.i, epsilon)
+    }
+}
+
+/// Compares two [`Mat3`]s element by element, within a relative tolerance.
+///
+/// Available with the `approx` feature.
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Mat3 {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.a.relative_eq(&other.a, epsilon, max_relative)
+            && self.b.relative_eq(&other.b, epsilon, max_relative)
+            && self.c.relative_eq(&other.c, epsilon, max_relative)
+            && self.d.relative_eq(&other.d, epsilon, max_relative)
+            && self.e.relative_eq(&other.e, epsilon, max_relative)
+            && self.f.relative_eq(&other.f, epsilon, max_relative)
+            && self.g.relative_eq(&other.g, epsilon, max_relative)
+            && self.h.relative_eq(&other.h, epsilon, max_relative)
+            && self.i.relative_eq(&other.i, epsilon, max_relative)
+    }
+}
+
+/// Converts from a column-major `mint` matrix. Available with the `mint` feature.
+///
+/// Each `mint` column becomes a column of the row-major [`Mat3`].
+#[cfg(feature = "mint")]
+impl From<mint::ColumnMatrix3<f64>> for Mat3 {
+    fn from(m: mint::ColumnMatrix3<f64>) -> Self {
+        Mat3::new(
+            m.x.x, m.y.x, m.z.x,
+            m.x.y, m.y.y, m.z.y,
+            m.x.z, m.y.z, m.z.z,
+        )
+    }
+}
+
+/// Converts to a column-major `mint` matrix. Available with the `mint` feature.
+#[cfg(feature = "mint")]
+impl From<Mat3> for mint::ColumnMatrix3<f64> {
+    fn from(m: Mat3) -> Self {
+        mint::ColumnMatrix3 {
+            x: mint::Vector3 { x: m.a, y: m.d, z: m.g },
+            y: mint::Vector3 { x: m.b, y: m.e, z: m.h },
+            z: mint::Vector3 { x: m.c, y: m.f, z: m.i },
+        }
+    }
+}
+
+/// Returns [`Mat3::ZERO`]. Available with the `num-traits` feature.
+#[cfg(feature = "num-traits")]
+impl num_traits::Zero for Mat3 {
+    fn zero() -> Self {
+        Mat3::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Mat3::ZERO
+    }
+}
+
+/// Returns [`Mat3::IDENTITY`]. Available with the `num-traits` feature.
+#[cfg(feature = "num-traits")]
+impl num_traits::One for Mat3 {
+    fn one() -> Self {
+        Mat3::IDENTITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{assert_abs_diff_eq, assert_relative_eq};
+
+    #[test]
+    fn test_add() {
+        let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(m + m, Mat3::new(2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0, 18.0));
+    }
+
+    #[test]
+    fn sub() {
+        let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(m - m, Mat3::ZERO);
+    }
+
+    #[test]
+    fn test_mat_mul() {
+        let a = Mat3::IDENTITY;
+        let b = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(a * b, b);
+    }
+
+    #[test]
     fn test_determinant() {
         let m = Mat3::new(1.0, 2.0, 3.0, 3.0, 2.0, 1.0, 2.0, 1.0, 3.0);
         assert_eq!(m.determinant(), -12.0);
@@ -217,4 +1198,436 @@ mod tests {
         let m = Mat3::new(1.0, 2.0, 3.0, 3.0, 2.0, 1.0, 2.0, 1.0, 3.0);
         assert_eq!(m.inverse(), Mat3::new(-5.0, 3.0, 4.0, 7.0, 3.0, -8.0, 1.0, -3.0, 4.0)/12.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_transpose() {
+        let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(m.transpose().transpose(), m);
+        assert_eq!(m.transpose().b, m.d);
+    }
+
+    #[test]
+    fn test_is_symmetric() {
+        let m = Mat3::new(1.0, 2.0, 3.0, 2.0, 4.0, 5.0, 3.0, 5.0, 6.0);
+        assert!(m.is_symmetric());
+        let n = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert!(!n.is_symmetric());
+    }
+
+    #[test]
+    fn test_symmetric_parts_sum() {
+        let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let sym = m.symmetric_part();
+        let anti = m.antisymmetric_part();
+        assert!(sym.is_symmetric());
+        assert_eq!(anti.transpose(), anti * -1.0);
+        assert_eq!(sym + anti, m);
+    }
+
+    #[test]
+    fn test_lu_decompose() {
+        let m = Mat3::new(2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0);
+        let (l, u, perm) = m.lu_decompose().unwrap();
+
+        // Build P * A by picking out the permuted rows of A.
+        let rows = m.to_rows_array();
+        let pa = Mat3::from_rows_array([rows[perm[0]], rows[perm[1]], rows[perm[2]]]);
+        assert_eq!(l * u, pa);
+
+        // L is unit lower-triangular, U is upper-triangular.
+        assert_eq!((l.a, l.e, l.i), (1.0, 1.0, 1.0));
+        assert_eq!((l.b, l.c, l.f), (0.0, 0.0, 0.0));
+        assert_eq!((u.d, u.g, u.h), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_lu_decompose_singular() {
+        let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert!(m.lu_decompose().is_none());
+        assert!(Mat3::ZERO.lu_decompose().is_none());
+    }
+
+    #[test]
+    fn test_lu_decompose_small_scale() {
+        let m = Mat3::IDENTITY * 1e-10;
+        let (l, u, perm) = m.lu_decompose().unwrap();
+        assert_eq!(l, Mat3::IDENTITY);
+        assert_eq!((u.a, u.e, u.i), (1e-10, 1e-10, 1e-10));
+        assert_eq!(perm, [0, 1, 2]);
+        assert!((1e-8 * m).lu_decompose().is_some());
+    }
+
+    #[test]
+    fn test_from_mat2() {
+        assert_eq!(Mat3::from_mat2(Mat2::IDENTITY), Mat3::IDENTITY);
+
+        let m = Mat3::from_mat2(Mat2::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(m, Mat3::new(1.0, 2.0, 0.0, 3.0, 4.0, 0.0, 0.0, 0.0, 1.0));
+        assert_eq!(m * Vec3::new(1.0, 1.0, 1.0), Vec3::new(3.0, 7.0, 1.0));
+    }
+
+    #[test]
+    fn test_map() {
+        let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(m.map(|x| x * 2.0), m * 2.0);
+    }
+
+    #[test]
+    fn test_component_mul() {
+        let a = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let b = Mat3::new(2.0, 0.0, 1.0, -1.0, 0.5, 2.0, 0.0, 1.0, 3.0);
+        let expected = Mat3::new(2.0, 0.0, 3.0, -4.0, 2.5, 12.0, 0.0, 8.0, 27.0);
+        assert_eq!(a.component_mul(&b), expected);
+        assert_ne!(a.component_mul(&b), a * b);
+    }
+
+    #[test]
+    fn test_frobenius_norm() {
+        assert_abs_diff_eq!(Mat3::IDENTITY.frobenius_norm(), 3.0_f64.sqrt(), epsilon = 1e-12);
+        assert_eq!(Mat3::ZERO.frobenius_norm(), 0.0);
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn test_approx() {
+        use approx::assert_abs_diff_ne;
+        let a = Mat3::IDENTITY;
+        let b = Mat3::IDENTITY * (1.0 + 1e-12);
+        assert_abs_diff_eq!(a, b);
+        assert_relative_eq!(a, b);
+        assert_abs_diff_ne!(a, a * 2.0);
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn test_mint_round_trip() {
+        let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let column: mint::ColumnMatrix3<f64> = m.into();
+        // The first column of the matrix is (a, d, g).
+        assert_eq!((column.x.x, column.x.y, column.x.z), (1.0, 4.0, 7.0));
+        assert_eq!(Mat3::from(column), m);
+    }
+
+    #[test]
+    fn test_is_rotation() {
+        let (s, c) = 0.3_f64.sin_cos();
+        let rotation = Mat3::new(c, 0.0, s, 0.0, 1.0, 0.0, -s, 0.0, c);
+        assert!(rotation.is_orthogonal());
+        assert!(rotation.is_right_handed());
+        assert!(rotation.is_rotation());
+    }
+
+    #[test]
+    fn test_mirror_is_not_rotation() {
+        let mirror = Mat3::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.0);
+        assert!(mirror.is_orthogonal());
+        assert!(!mirror.is_right_handed());
+        assert!(!mirror.is_rotation());
+    }
+
+    #[test]
+    fn test_scale_is_not_rotation() {
+        let scale = Mat3::IDENTITY * 2.0;
+        assert!(scale.is_right_handed());
+        assert!(!scale.is_rotation());
+    }
+
+    #[test]
+    fn test_mul_by_ref() {
+        let m = Mat3::new(1.0, 2.0, 3.0, 3.0, 2.0, 1.0, 2.0, 1.0, 3.0);
+        let n = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let v = Vec3::new(1.0, -1.0, 2.0);
+
+        // Borrow up front, as a caller iterating over references would.
+        let (m_ref, n_ref, v_ref) = (&m, &n, &v);
+        assert_eq!(m_ref * v_ref, m * v);
+        assert_eq!(m_ref * v, m * v);
+        assert_eq!(m_ref * n_ref, m * n);
+        assert_eq!(m_ref * n, m * n);
+        assert_eq!(m * n_ref, m * n);
+    }
+
+    #[test]
+    fn test_transform_2d_helpers() {
+        let p = Point2D::new(1.0, 2.0);
+        let t = Mat3::translation_2d(Vec2::new(3.0, -1.0));
+        let r = Mat3::rotation_2d(std::f64::consts::FRAC_PI_2);
+        let s = Mat3::scale_2d(Vec2::new(2.0, 3.0));
+
+        assert_eq!(t.transform_point_2d(p), Point2D::new(4.0, 1.0));
+        assert_eq!(t.transform_vector_2d(p), p);
+        assert_eq!(r.transform_point_2d(p), Point2D::new(-2.0, 1.0));
+        assert_eq!(s.transform_point_2d(p), Point2D::new(2.0, 6.0));
+        assert_eq!((t * r).transform_point_2d(p), Point2D::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_from_trs_2d() {
+        let translation = Vec2::new(3.0, -1.0);
+        let rotation = 0.6;
+        let scale = Vec2::new(2.0, 0.5);
+        let composed = Mat3::translation_2d(translation)
+            * Mat3::rotation_2d(rotation)
+            * Mat3::scale_2d(scale);
+        let m = Mat3::from_trs_2d(translation, rotation, scale);
+
+        assert_eq!(m, composed);
+        let p = Point2D::new(1.0, 2.0);
+        assert_eq!(m.transform_point_2d(p), composed.transform_point_2d(p));
+    }
+
+    #[test]
+    fn test_from_axis_angle() {
+        let m = Mat3::from_axis_angle(Vec3::new(0.0, 0.0, 2.0), std::f64::consts::FRAC_PI_2);
+        assert_eq!(m * Vec3::UNIT_X, Vec3::UNIT_Y);
+        assert_eq!(m * Vec3::UNIT_Z, Vec3::UNIT_Z);
+        assert!(m.is_rotation());
+
+        let axis = Vec3::ONE;
+        let third = Mat3::from_axis_angle(axis, 2.0 * std::f64::consts::FRAC_PI_3);
+        assert_eq!(third * Vec3::UNIT_X, Vec3::UNIT_Y);
+    }
+
+    #[test]
+    fn test_rotation_between() {
+        let m = Mat3::rotation_between(Vec3::UNIT_X, Vec3::UNIT_Y);
+        assert_eq!(m * Vec3::UNIT_X, Vec3::UNIT_Y);
+        assert!(m.is_rotation());
+
+        let from = Vec3::new(1.0, 2.0, 3.0);
+        let to = Vec3::new(-2.0, 0.5, 1.0);
+        let m = Mat3::rotation_between(from, to);
+        assert_eq!(m * from.normalize(), to.normalize());
+    }
+
+    #[test]
+    fn test_rotation_between_anti_parallel() {
+        let m = Mat3::rotation_between(Vec3::UNIT_X, -Vec3::UNIT_X);
+        assert_eq!(m * Vec3::UNIT_X, -Vec3::UNIT_X);
+        assert!(m.is_rotation());
+    }
+
+    #[test]
+    fn test_rotation_between_parallel() {
+        let m = Mat3::rotation_between(Vec3::UNIT_X, Vec3::new(3.0, 0.0, 0.0));
+        assert_eq!(m, Mat3::IDENTITY);
+        assert_eq!(Mat3::rotation_between(Vec3::ZERO, Vec3::UNIT_X), Mat3::IDENTITY);
+    }
+
+    #[test]
+    fn test_scale_uniform() {
+        assert_eq!(Mat3::scale_uniform(2.0) * Vec3::ONE, Vec3::new(2.0, 2.0, 2.0));
+        assert_eq!(Mat3::scale_uniform(1.0), Mat3::IDENTITY);
+    }
+
+    #[test]
+    fn test_from_diagonal() {
+        assert_eq!(Mat3::from_diagonal(Vec3::ONE), Mat3::IDENTITY);
+        let d = Vec3::new(-1.0, 2.5, 4.0);
+        assert_eq!(Mat3::from_diagonal(d).diagonal(), d);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits() {
+        use num_traits::{One, Zero};
+        assert!(Mat3::zero().is_zero());
+        assert_eq!(Mat3::one(), Mat3::IDENTITY);
+        assert!(Mat3::one().is_one());
+    }
+
+    #[test]
+    fn test_symmetric_eigen_diagonal() {
+        let m = Mat3::from_diagonal(Vec3::new(3.0, 1.0, 2.0));
+        let (values, vectors) = m.symmetric_eigen();
+        assert_eq!(values, Vec3::new(3.0, 2.0, 1.0));
+        assert_eq!(vectors * Vec3::UNIT_X, Vec3::UNIT_X);
+        assert_eq!(vectors * Vec3::UNIT_Y, Vec3::UNIT_Z);
+        assert_eq!(vectors * Vec3::UNIT_Z, Vec3::UNIT_Y);
+    }
+
+    #[test]
+    fn test_symmetric_eigen_known() {
+        let m = Mat3::new(
+            4.0, 1.0, -2.0,
+            1.0, 2.0, 0.0,
+            -2.0, 0.0, 3.0,
+        );
+        let (values, vectors) = m.symmetric_eigen();
+        assert!(vectors.is_orthogonal());
+        assert_abs_diff_eq!(values.component_sum(), m.diagonal().component_sum(), epsilon = 1e-9);
+        assert_abs_diff_eq!(values.x * values.y * values.z, m.determinant(), epsilon = 1e-9);
+        assert!(values.x >= values.y && values.y >= values.z);
+        for (k, e) in [Vec3::UNIT_X, Vec3::UNIT_Y, Vec3::UNIT_Z].into_iter().enumerate() {
+            let v = vectors * e;
+            assert_eq!(m * v, values.to_array()[k] * v);
+        }
+        assert_eq!(vectors * Mat3::from_diagonal(values) * vectors.transpose(), m);
+    }
+
+    #[test]
+    fn test_symmetric_eigen_small_scale() {
+        let m = Mat3::new(
+            1e-12, 1e-12, 0.0,
+            1e-12, 1e-12, 0.0,
+            0.0, 0.0, 1e-12,
+        );
+        let (values, _) = m.symmetric_eigen();
+        assert_relative_eq!(values.x, 2e-12, max_relative = 1e-9);
+        assert_relative_eq!(values.y, 1e-12, max_relative = 1e-9);
+        assert_abs_diff_eq!(values.z, 0.0, epsilon = 1e-24);
+    }
+
+    #[test]
+    fn test_snap_small_to_zero() {
+        let noise = Mat3::new(
+            1e-16, -1e-16, 0.0,
+            0.0, 1e-16, 0.0,
+            0.0, 0.0, -1e-16,
+        );
+        let m = Mat3::rotation_2d(0.5) + noise;
+        let clean = m.snap_small_to_zero(1e-12);
+        assert_eq!(clean, Mat3::rotation_2d(0.5));
+        assert_eq!([clean.c, clean.f, clean.g, clean.h], [0.0; 4]);
+    }
+
+    #[test]
+    fn test_add_sub_assign() {
+        let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let mut acc = m;
+        acc += m;
+        assert_eq!(acc, m + m);
+        acc -= Mat3::IDENTITY;
+        assert_eq!(acc, m + m - Mat3::IDENTITY);
+    }
+
+    #[test]
+    fn test_about_point_keeps_pivot_fixed() {
+        let pivot = Vec2::new(3.0, -2.0);
+        let scale = Mat3::scale_about_point(pivot, Vec2::new(2.0, 0.5));
+        let rotate = Mat3::rotate_about_point(pivot, 1.2);
+        assert_eq!(scale.transform_point_2d(pivot), pivot);
+        assert_eq!(rotate.transform_point_2d(pivot), pivot);
+
+        let p = Point2D::new(5.0, 2.0);
+        assert_eq!(scale.transform_point_2d(p), Point2D::new(7.0, 0.0));
+        assert_eq!(rotate.transform_point_2d(p), pivot + Mat2::rotation(1.2) * (p - pivot));
+        assert_eq!(Mat3::scale_about_point(Vec2::ZERO, Vec2::ONE * 2.0), Mat3::scale_2d(Vec2::ONE * 2.0));
+    }
+
+    #[test]
+    fn test_determinant_stable() {
+        let m = Mat3::new(
+            2.0, -1.0, 0.5,
+            4.0, 3.0, -2.0,
+            -1.0, 0.0, 6.0,
+        );
+        assert_abs_diff_eq!(m.determinant_stable(), m.determinant(), epsilon = 1e-12);
+        assert_eq!(Mat3::IDENTITY.determinant_stable(), 1.0);
+        assert_eq!(Mat3::new(0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0).determinant_stable(), -1.0);
+        assert_eq!(Mat3::ZERO.determinant_stable(), 0.0);
+        assert_eq!(Mat3::from_diagonal(Vec3::new(1e-10, 1.0, 1.0)).determinant_stable(), 1e-10);
+        assert_eq!(Mat3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 0.0, 1.0).determinant_stable(), 0.0);
+
+        // Exact determinant is 1e8, but the cofactor expansion cancels to zero.
+        let ill = Mat3::new(
+            1e8, 1e8, 1e8,
+            1e8, 1e8 + 1.0, 1e8,
+            1e8, 1e8, 1e8 + 1.0,
+        );
+        assert_eq!(ill.determinant_stable(), 1e8);
+        assert_eq!(ill.determinant(), 0.0);
+    }
+
+    #[test]
+    fn test_is_identity_and_diagonal() {
+        assert!(Mat3::IDENTITY.is_identity());
+        assert!(Mat3::IDENTITY.is_diagonal());
+
+        let scale = Mat3::from_diagonal(Vec3::new(2.0, -1.0, 0.5));
+        assert!(!scale.is_identity());
+        assert!(scale.is_diagonal());
+
+        let rotation = Mat3::from_axis_angle(Vec3::new(1.0, 1.0, 0.0), 0.9);
+        assert!(!rotation.is_identity());
+        assert!(!rotation.is_diagonal());
+        assert!((rotation * rotation.transpose()).is_identity());
+    }
+
+    #[test]
+    fn test_cholesky_reconstructs() {
+        let m = Mat3::new(
+            4.0, 12.0, -16.0,
+            12.0, 37.0, -43.0,
+            -16.0, -43.0, 98.0,
+        );
+        let l = m.cholesky().unwrap();
+        assert_eq!(l, Mat3::new(
+            2.0, 0.0, 0.0,
+            6.0, 1.0, 0.0,
+            -8.0, 5.0, 3.0,
+        ));
+        assert_eq!(l * l.transpose(), m);
+        assert_eq!(Mat3::IDENTITY.cholesky(), Some(Mat3::IDENTITY));
+    }
+
+    #[test]
+    fn test_cholesky_rejects_non_spd() {
+        let not_symmetric = Mat3::new(4.0, 1.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 4.0);
+        assert_eq!(not_symmetric.cholesky(), None);
+        let indefinite = Mat3::from_diagonal(Vec3::new(1.0, -2.0, 3.0));
+        assert_eq!(indefinite.cholesky(), None);
+        let singular = Mat3::new(1.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+        assert_eq!(singular.cholesky(), None);
+    }
+
+    #[test]
+    fn test_cholesky_large_magnitude() {
+        // Off-diagonals differ by rounding noise, a relative error of about 1e-15.
+        let m = Mat3::new(
+            4e6, 2e6, 0.0,
+            2e6 + 3e-9, 5e6, 0.0,
+            0.0, 0.0, 9e6,
+        );
+        let l = m.cholesky().unwrap();
+        assert_relative_eq!(l.a, 2e3, max_relative = 1e-12);
+        assert_relative_eq!(l.d, 1e3, max_relative = 1e-12);
+        assert_relative_eq!(l.e, 2e3, max_relative = 1e-12);
+        assert_relative_eq!(l.i, 3e3, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn test_rows_and_cols_arrays() {
+        let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(Mat3::from_rows_array(m.to_rows_array()), m);
+        assert_eq!(Mat3::from_cols_array(m.to_cols_array()), m);
+        assert_eq!(Mat3::from_cols_array(m.to_rows_array()), m.transpose());
+        assert_eq!(m.to_cols_array()[0], [1.0, 4.0, 7.0]);
+    }
+
+    #[test]
+    fn test_change_of_basis_y_up_to_z_up() {
+        let y_up = Basis3::new(Vec3::UNIT_X, -Vec3::UNIT_Z, Vec3::UNIT_Y);
+        let z_up = Basis3::new(Vec3::UNIT_X, Vec3::UNIT_Y, Vec3::UNIT_Z);
+        let m = Mat3::change_of_basis(&y_up, &z_up);
+        assert!(m.is_rotation());
+        assert_eq!(m * Vec3::new(1.0, 2.0, 3.0), Vec3::new(1.0, -3.0, 2.0));
+        assert_eq!(m * y_up.bitangent, z_up.bitangent);
+        assert_eq!(Mat3::change_of_basis(&z_up, &y_up), m.transpose());
+    }
+
+    #[test]
+    fn test_ortho_2d_corners() {
+        let m = Mat3::ortho_2d(-3.0, 5.0, 10.0, 4.0);
+        let corners = [
+            (Point2D::new(-3.0, 4.0), Point2D::new(-1.0, 1.0)),
+            (Point2D::new(5.0, 4.0), Point2D::new(1.0, 1.0)),
+            (Point2D::new(-3.0, 10.0), Point2D::new(-1.0, -1.0)),
+            (Point2D::new(5.0, 10.0), Point2D::new(1.0, -1.0)),
+        ];
+        for (world, ndc) in corners {
+            assert_eq!(m.transform_point_2d(world), ndc);
+        }
+        assert_eq!(m.transform_point_2d(Point2D::new(1.0, 7.0)), Point2D::ZERO);
+    }
+}
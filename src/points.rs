@@ -0,0 +1,178 @@
+//! Point cloud utilities.
+//!
+//! Point clouds are represented as a slice of [`Point3D`]s (or [`Point2D`]s), in
+//! no particular order.
+
+use crate::{Mat3, Point2D, Point3D, Vec3};
+
+const EPSILON: f64 = 1e-9;
+
+/// Returns the **centroid** of a point cloud, the component-wise average of
+/// its points.
+///
+/// Returns [`Point3D::ZERO`] for an empty slice.
+///
+/// # Examples
+/// ```
+/// use lars::{centroid, Point3D};
+/// let points = [Point3D::new(0.0, 0.0, 0.0), Point3D::new(2.0, 4.0, -6.0)];
+/// assert_eq!(centroid(&points), Point3D::new(1.0, 2.0, -3.0));
+/// ```
+pub fn centroid(points: &[Point3D]) -> Point3D {
+    if points.is_empty() {
+        return Point3D::ZERO;
+    }
+    points.iter().fold(Point3D::ZERO, |acc, &p| acc + p) / points.len() as f64
+}
+
+/// Returns the **centroid** of a set of 2D points, the component-wise average
+/// of the points.
+///
+/// This is the average of the vertices, not the area centroid of the polygon
+/// they outline. Returns [`Point2D::ZERO`] for an empty slice.
+///
+/// # Examples
+/// ```
+/// use lars::{centroid_2d, Point2D};
+/// let points = [Point2D::new(0.0, 0.0), Point2D::new(2.0, 4.0)];
+/// assert_eq!(centroid_2d(&points), Point2D::new(1.0, 2.0));
+/// ```
+pub fn centroid_2d(points: &[Point2D]) -> Point2D {
+    if points.is_empty() {
+        return Point2D::ZERO;
+    }
+    points.iter().fold(Point2D::ZERO, |acc, &p| acc + p) / points.len() as f64
+}
+
+/// Returns the **weighted average** of a set of vectors, given as
+/// `(vector, weight)` pairs.
+///
+/// Each vector is scaled by its weight and the sum is divided by the total
+/// weight. Returns [`Vec3::ZERO`] if the weights sum to zero (including for an
+/// empty slice). The total counts as zero when it is within epsilon of the sum
+/// of the absolute weights, so weights that cancel out up to rounding are
+/// caught, while uniformly tiny weights are still averaged.
+///
+/// # Examples
+/// ```
+/// use lars::{weighted_average, Vec3};
+/// let items = [(Vec3::new(0.0, 0.0, 0.0), 1.0), (Vec3::new(4.0, 0.0, 0.0), 3.0)];
+/// assert_eq!(weighted_average(&items), Vec3::new(3.0, 0.0, 0.0));
+/// ```
+pub fn weighted_average(items: &[(Vec3, f64)]) -> Vec3 {
+    let total: f64 = items.iter().map(|&(_, w)| w).sum();
+    let magnitude: f64 = items.iter().map(|&(_, w)| w.abs()).sum();
+    if total.abs() <= EPSILON * magnitude {
+        return Vec3::ZERO;
+    }
+    items.iter().fold(Vec3::ZERO, |acc, &(v, w)| acc + v * w) / total
+}
+
+/// Returns the **covariance matrix** of a point cloud.
+///
+/// Each point is offset by the [`centroid`] `c` of the cloud, and the outer products of
+/// the offsets are averaged:
+/// \[
+/// C = \frac{1}{n} \sum_{i} (p_i - c)(p_i - c)^T
+/// \]
+/// The result is symmetric. Its eigenvectors are the principal axes of the
+/// cloud, and the one with the smallest eigenvalue is the normal of the
+/// best-fit plane.
+///
+/// Returns [`Mat3::ZERO`] for an empty slice.
+///
+/// # Examples
+/// ```
+/// use lars::{covariance, Mat3, Point3D};
+/// let points = [Point3D::new(-1.0, 0.0, 0.0), Point3D::new(1.0, 0.0, 0.0)];
+/// assert_eq!(covariance(&points), Mat3::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0));
+/// ```
+pub fn covariance(points: &[Point3D]) -> Mat3 {
+    if points.is_empty() {
+        return Mat3::ZERO;
+    }
+
+    let mean = centroid(points);
+    let mut sum = Mat3::ZERO;
+    for &p in points {
+        let d = p - mean;
+        sum += d.outer(&d);
+    }
+
+    sum / points.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_centroid_symmetric() {
+        let center = Point3D::new(1.0, -2.0, 3.0);
+        let points = [Point3D::UNIT_X, -Point3D::UNIT_X, Point3D::UNIT_Y, -Point3D::UNIT_Y]
+            .map(|p| center + 2.5 * p);
+        assert_eq!(centroid(&points), center);
+        assert_eq!(centroid(&[]), Point3D::ZERO);
+    }
+
+    #[test]
+    fn test_centroid_2d_symmetric() {
+        let center = Point2D::new(4.0, 1.0);
+        let points = [Point2D::UNIT_X, -Point2D::UNIT_X, Point2D::UNIT_Y, -Point2D::UNIT_Y]
+            .map(|p| center + p);
+        assert_eq!(centroid_2d(&points), center);
+        assert_eq!(centroid_2d(&[]), Point2D::ZERO);
+    }
+
+    #[test]
+    fn test_covariance_axis_aligned() {
+        let points = [
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(-1.0, 0.0, 0.0),
+            Point3D::new(0.0, 2.0, 0.0),
+            Point3D::new(0.0, -2.0, 0.0),
+            Point3D::new(0.0, 0.0, 3.0),
+            Point3D::new(0.0, 0.0, -3.0),
+        ];
+        let expected = Mat3::new(
+            2.0 / 6.0, 0.0, 0.0,
+            0.0, 8.0 / 6.0, 0.0,
+            0.0, 0.0, 18.0 / 6.0,
+        );
+        assert_eq!(covariance(&points), expected);
+    }
+
+    #[test]
+    fn test_covariance_ignores_offset() {
+        let points = [Point3D::new(1.0, 2.0, 3.0), Point3D::new(3.0, 2.0, 3.0)];
+        let shifted = points.map(|p| p + Point3D::new(10.0, -4.0, 7.0));
+        assert_eq!(covariance(&points), covariance(&shifted));
+        assert!(covariance(&points).is_symmetric());
+    }
+
+    #[test]
+    fn test_covariance_empty() {
+        assert_eq!(covariance(&[]), Mat3::ZERO);
+    }
+
+    #[test]
+    fn test_weighted_average_equal_weights() {
+        let points = [
+            Point3D::new(1.0, 2.0, 3.0),
+            Point3D::new(-4.0, 0.5, 2.0),
+            Point3D::new(0.0, 7.0, -1.0),
+        ];
+        let items = points.map(|p| (p, 2.0));
+        assert_eq!(weighted_average(&items), centroid(&points));
+    }
+
+    #[test]
+    fn test_weighted_average_zero_weight() {
+        assert_eq!(weighted_average(&[]), Vec3::ZERO);
+        assert_eq!(weighted_average(&[(Vec3::ONE, 1.0), (Vec3::UNIT_X, -1.0)]), Vec3::ZERO);
+        assert_eq!(weighted_average(&[(Vec3::ONE, 0.0), (Vec3::UNIT_X, 2.0)]), Vec3::UNIT_X);
+        assert_eq!(weighted_average(&[(Vec3::ONE, 1e-10), (-Vec3::ONE, 3e-10)]), -0.5 * Vec3::ONE);
+        let cancelling = [(Vec3::UNIT_X, 0.1), (Vec3::UNIT_Y, 0.2), (Vec3::UNIT_Z, -0.3)];
+        assert_eq!(weighted_average(&cancelling), Vec3::ZERO);
+    }
+}
@@ -0,0 +1,321 @@
+//! 2D polygon utilities.
+//!
+//! Polygons are represented as a slice of [`Point2D`] vertices, listed in order
+//! around the boundary. The last vertex is implicitly joined back to the first.
+
+use crate::Point2D;
+
+const EPSILON: f64 = 1e-9;
+
+/// Returns the **signed area** of a polygon using the shoelace formula.
+///
+/// A positive result means the vertices wind **counter-clockwise**, a negative
+/// result means they wind **clockwise**.
+///
+/// Computed as:
+/// \[
+/// A = \frac{1}{2} \sum_{i} p_i \times p_{i+1}
+/// \]
+///
+/// Returns `0.0` if fewer than 3 points are given.
+///
+/// # Examples
+/// ```
+/// use lars::{polygon_signed_area, Point2D};
+/// let triangle = [
+///     Point2D::new(0.0, 0.0),
+///     Point2D::new(2.0, 0.0),
+///     Point2D::new(0.0, 2.0),
+/// ];
+/// assert_eq!(polygon_signed_area(&triangle), 2.0);
+/// ```
+pub fn polygon_signed_area(points: &[Point2D]) -> f64 {
+    if points.len() < 3 {
+        return 0.0;
+    }
+
+    let mut sum = 0.0;
+    for (i, p) in points.iter().enumerate() {
+        let next = &points[(i + 1) % points.len()];
+        sum += p.cross(next);
+    }
+
+    0.5 * sum
+}
+
+/// Returns `true` if the polygon winds **clockwise**, i.e. its
+/// [signed area](polygon_signed_area) is negative.
+///
+/// Degenerate polygons (fewer than 3 points, or zero area) are not clockwise.
+///
+/// # Examples
+/// ```
+/// use lars::{is_clockwise, Point2D};
+/// let mut triangle = [
+///     Point2D::new(0.0, 0.0),
+///     Point2D::new(1.0, 0.0),
+///     Point2D::new(0.0, 1.0),
+/// ];
+/// assert!(!is_clockwise(&triangle));
+/// triangle.reverse();
+/// assert!(is_clockwise(&triangle));
+/// ```
+pub fn is_clockwise(points: &[Point2D]) -> bool {
+    polygon_signed_area(points) < 0.0
+}
+
+/// Returns `true` if the polygon is **convex**.
+///
+/// The cross product of consecutive edges is taken at every vertex; the
+/// polygon is convex if they all have the same sign, so it turns the same way
+/// at every corner. Either winding order is accepted.
+///
+/// Collinear vertices are ignored. A turn counts as zero when it is below
+/// epsilon relative to the lengths of the edges, so the result doesn't depend
+/// on the size of the polygon. Fewer than 3 points, or
+/// points that are all collinear, don't make a polygon and return `false`. The
+/// polygon is assumed to be simple: a self-intersecting star turns the same way
+/// at every vertex and is reported as convex.
+///
+/// # Examples
+/// ```
+/// use lars::{is_convex, Point2D};
+/// let square = [
+///     Point2D::new(0.0, 0.0),
+///     Point2D::new(1.0, 0.0),
+///     Point2D::new(1.0, 1.0),
+///     Point2D::new(0.0, 1.0),
+/// ];
+/// assert!(is_convex(&square));
+/// ```
+pub fn is_convex(points: &[Point2D]) -> bool {
+    if points.len() < 3 {
+        return false;
+    }
+
+    let n = points.len();
+    let mut sign = 0.0;
+    for i in 0..n {
+        let (a, b, c) = (points[i], points[(i + 1) % n], points[(i + 2) % n]);
+        let turn = turn(a, b, c);
+        if turn.abs() <= EPSILON * (b - a).mag() * (c - a).mag() {
+            continue;
+        }
+        if sign == 0.0 {
+            sign = turn.signum();
+        } else if turn.signum() != sign {
+            return false;
+        }
+    }
+
+    sign != 0.0
+}
+
+/// Returns the **convex hull** of a set of points, the smallest convex polygon
+/// containing them all, using Andrew's monotone chain algorithm.
+///
+/// The points are sorted by `x` (then `y`), and the lower and upper halves of
+/// the hull are built in one pass each, dropping any point that would make a
+/// clockwise (or straight) turn. This runs in `O(n log n)`.
+///
+/// The hull vertices are returned in **counter-clockwise** order, starting
+/// from the leftmost point. Points lying on a hull edge are not included.
+/// Exact duplicate points are removed, so fewer than 3 distinct points are
+/// returned as they are, and all-collinear points give just the two end points.
+///
+/// # Examples
+/// ```
+/// use lars::{convex_hull, Point2D};
+/// let points = [
+///     Point2D::new(0.0, 0.0),
+///     Point2D::new(2.0, 0.0),
+///     Point2D::new(1.0, 0.5),
+///     Point2D::new(1.0, 2.0),
+/// ];
+/// assert_eq!(
+///     convex_hull(&points),
+///     vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(1.0, 2.0)],
+/// );
+/// ```
+pub fn convex_hull(points: &[Point2D]) -> Vec<Point2D> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup_by(|a, b| a.exact_eq(b));
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut hull: Vec<Point2D> = Vec::with_capacity(sorted.len() + 1);
+    for &p in &sorted {
+        while hull.len() >= 2 && turn(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+
+    // The upper half must not pop back into the finished lower half.
+    let lower_len = hull.len() + 1;
+    for &p in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len && turn(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+
+    // The last point is the first one again.
+    hull.pop();
+    hull
+}
+
+/// Returns the cross product `(b - a) × (c - a)`, which is positive if
+/// `a → b → c` turns counter-clockwise and negative if it turns clockwise.
+fn turn(a: Point2D, b: Point2D, c: Point2D) -> f64 {
+    (b - a).cross(&(c - a))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit_square() -> [Point2D; 4] {
+        [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 0.0),
+            Point2D::new(1.0, 1.0),
+            Point2D::new(0.0, 1.0),
+        ]
+    }
+
+    #[test]
+    fn test_signed_area_ccw() {
+        assert_eq!(polygon_signed_area(&unit_square()), 1.0);
+    }
+
+    #[test]
+    fn test_signed_area_cw() {
+        let mut square = unit_square();
+        square.reverse();
+        assert_eq!(polygon_signed_area(&square), -1.0);
+    }
+
+    #[test]
+    fn test_signed_area_degenerate() {
+        let line = [Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)];
+        assert_eq!(polygon_signed_area(&line), 0.0);
+    }
+
+    #[test]
+    fn test_is_convex_square() {
+        assert!(is_convex(&unit_square()));
+        let mut square = unit_square();
+        square.reverse();
+        assert!(is_convex(&square));
+    }
+
+    #[test]
+    fn test_is_convex_concave_l() {
+        let l_shape = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(2.0, 0.0),
+            Point2D::new(2.0, 1.0),
+            Point2D::new(1.0, 1.0),
+            Point2D::new(1.0, 2.0),
+            Point2D::new(0.0, 2.0),
+        ];
+        assert!(!is_convex(&l_shape));
+    }
+
+    #[test]
+    fn test_is_convex_small_scale() {
+        assert!(is_convex(&unit_square().map(|p| p * 1e-5)));
+        let l_shape = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(2.0, 0.0),
+            Point2D::new(2.0, 1.0),
+            Point2D::new(1.0, 1.0),
+            Point2D::new(1.0, 2.0),
+            Point2D::new(0.0, 2.0),
+        ];
+        assert!(!is_convex(&l_shape.map(|p| p * 1e-5)));
+    }
+
+    #[test]
+    fn test_is_convex_degenerate() {
+        let with_collinear = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 0.0),
+            Point2D::new(2.0, 0.0),
+            Point2D::new(2.0, 2.0),
+        ];
+        assert!(is_convex(&with_collinear));
+
+        let line = [Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(2.0, 2.0)];
+        assert!(!is_convex(&line));
+        assert!(!is_convex(&line[..2]));
+    }
+
+    #[test]
+    fn test_is_clockwise() {
+        let mut triangle = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(4.0, 0.0),
+            Point2D::new(0.0, 3.0),
+        ];
+        assert!(!is_clockwise(&triangle));
+        triangle.reverse();
+        assert!(is_clockwise(&triangle));
+    }
+
+    #[test]
+    fn test_convex_hull_square_with_interior() {
+        let mut points = unit_square().to_vec();
+        points.extend([
+            Point2D::new(0.5, 0.5),
+            Point2D::new(0.25, 0.75),
+            Point2D::new(0.5, 0.0),
+            Point2D::new(1.0, 1.0),
+        ]);
+        points.reverse();
+        assert_eq!(convex_hull(&points), unit_square().to_vec());
+    }
+
+    #[test]
+    fn test_convex_hull_small_scale() {
+        let square = unit_square().map(|p| p * 1e-5);
+        let mut points = square.to_vec();
+        points.push(Point2D::new(0.5e-5, 0.5e-5));
+        let hull = convex_hull(&points);
+        assert_eq!(hull.len(), 4);
+        assert_eq!(hull, square.to_vec());
+    }
+
+    #[test]
+    fn test_convex_hull_tiny_scale() {
+        let square = unit_square().map(|p| p * 1e-10);
+        let hull = convex_hull(&square);
+        assert_eq!(hull.len(), 4);
+        assert!(hull.iter().zip(&square).all(|(a, b)| a.exact_eq(b)));
+    }
+
+    #[test]
+    fn test_convex_hull_triangle() {
+        let triangle = [
+            Point2D::new(3.0, 1.0),
+            Point2D::new(0.0, 4.0),
+            Point2D::new(-1.0, 0.0),
+        ];
+        let hull = convex_hull(&triangle);
+        assert_eq!(hull, vec![triangle[2], triangle[0], triangle[1]]);
+        assert!(!is_clockwise(&hull));
+    }
+
+    #[test]
+    fn test_convex_hull_degenerate() {
+        assert_eq!(convex_hull(&[]), Vec::new());
+        let p = Point2D::new(1.0, 2.0);
+        assert_eq!(convex_hull(&[p, p]), vec![p]);
+
+        let line = [Point2D::new(2.0, 2.0), Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)];
+        assert_eq!(convex_hull(&line), vec![line[1], line[0]]);
+    }
+}
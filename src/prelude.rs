@@ -0,0 +1,15 @@
+//! Commonly used types, importable in one line.
+//!
+//! # Examples
+//! ```
+//! use lars::prelude::*;
+//!
+//! let v = Vec3::new(1.0, 2.0, 3.0);
+//! let m = Mat3::IDENTITY;
+//! assert_eq!(m * v, v);
+//! ```
+
+pub use crate::{
+    Basis3, Colour, Degrees, Mat2, Mat3, Point2D, Point3D, Radians, Scalar, Segment2D, Sphere,
+    Transform2D, Vec2, Vec3,
+};
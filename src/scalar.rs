@@ -0,0 +1,110 @@
+//! Scalar utilities.
+//!
+//! Defines the [`Scalar`] type used for every component in the crate, along with
+//! small helper functions that the vector and matrix types build on.
+
+pub use std::f64::consts::{PI, TAU};
+
+/// The floating point type used for all components.
+pub type Scalar = f64;
+
+/// Converts an angle in **degrees** to **radians**.
+///
+/// # Examples
+/// ```
+/// use lars::{to_radians, PI};
+/// assert_eq!(to_radians(180.0), PI);
+/// ```
+pub fn to_radians(degrees: Scalar) -> Scalar {
+    degrees.to_radians()
+}
+
+/// Converts an angle in **radians** to **degrees**.
+///
+/// # Examples
+/// ```
+/// use lars::{to_degrees, PI};
+/// assert_eq!(to_degrees(PI), 180.0);
+/// ```
+pub fn to_degrees(radians: Scalar) -> Scalar {
+    radians.to_degrees()
+}
+
+/// **Linearly interpolates** between `a` and `b` by `t`.
+///
+/// `t = 0.0` returns `a` and `t = 1.0` returns `b`.
+///
+/// # Examples
+/// ```
+/// use lars::lerp;
+/// assert_eq!(lerp(2.0, 4.0, 0.5), 3.0);
+/// ```
+pub fn lerp(a: Scalar, b: Scalar, t: Scalar) -> Scalar {
+    a + t * (b - a)
+}
+
+/// Restricts `x` to the range `[lo, hi]`.
+///
+/// # Panics
+/// Panics if `lo > hi`.
+///
+/// # Examples
+/// ```
+/// use lars::clamp;
+/// assert_eq!(clamp(1.5, 0.0, 1.0), 1.0);
+/// assert_eq!(clamp(-1.5, 0.0, 1.0), 0.0);
+/// ```
+pub fn clamp(x: Scalar, lo: Scalar, hi: Scalar) -> Scalar {
+    x.clamp(lo, hi)
+}
+
+/// Returns `true` if `a` and `b` differ by no more than `eps`.
+///
+/// # Examples
+/// ```
+/// use lars::approx_eq;
+/// assert!(approx_eq(0.1 + 0.2, 0.3, 1e-9));
+/// ```
+pub fn approx_eq(a: Scalar, b: Scalar, eps: Scalar) -> bool {
+    (a - b).abs() <= eps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_lerp() {
+        assert_eq!(lerp(1.0, 3.0, 0.0), 1.0);
+        assert_eq!(lerp(1.0, 3.0, 1.0), 3.0);
+        assert_eq!(lerp(1.0, 3.0, 0.5), 2.0);
+        assert_eq!(lerp(1.0, 3.0, 2.0), 5.0);
+    }
+
+    #[test]
+    fn test_clamp() {
+        assert_eq!(clamp(0.5, 0.0, 1.0), 0.5);
+        assert_eq!(clamp(-2.0, -1.0, 1.0), -1.0);
+        assert_eq!(clamp(2.0, -1.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        assert!(approx_eq(1.0, 1.0 + 1e-12, 1e-9));
+        assert!(!approx_eq(1.0, 1.1, 1e-9));
+    }
+
+    #[test]
+    fn test_to_radians() {
+        assert_abs_diff_eq!(to_radians(180.0), PI, epsilon = 1e-12);
+        assert_abs_diff_eq!(to_radians(360.0), TAU, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_angle_round_trip() {
+        for degrees in [-90.0, 0.0, 45.0, 123.4, 720.0] {
+            assert_abs_diff_eq!(to_degrees(to_radians(degrees)), degrees, epsilon = 1e-9);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Line segment utilities.
+//!
+//! Provides a 2D line segment type [`Segment2D`] with support for finding
+//! where two segments cross.
+
+use crate::Point2D;
+use derive_more::Constructor;
+
+const EPSILON: f64 = 1e-9;
+
+/// A straight line segment between two 2D points.
+///
+/// # Examples
+/// ```
+/// use lars::{Point2D, Segment2D};
+/// let s = Segment2D::new(Point2D::new(0.0, 0.0), Point2D::new(3.0, 4.0));
+/// assert_eq!(s.length(), 5.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Constructor)]
+pub struct Segment2D {
+    /// The point the segment starts at.
+    pub start: Point2D,
+    /// The point the segment ends at.
+    pub end: Point2D,
+}
+
+impl Segment2D {
+    /// Returns the length of the segment.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Point2D, Segment2D};
+    /// let s = Segment2D::new(Point2D::new(1.0, 1.0), Point2D::new(1.0, 3.0));
+    /// assert_eq!(s.length(), 2.0);
+    /// ```
+    pub fn length(&self) -> f64 {
+        self.start.dist(&self.end)
+    }
+
+    /// Returns the point a fraction `t` of the way along the segment.
+    ///
+    /// `t = 0.0` returns `start` and `t = 1.0` returns `end`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Point2D, Segment2D};
+    /// let s = Segment2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 2.0));
+    /// assert_eq!(s.point_at(0.5), Point2D::new(2.0, 1.0));
+    /// ```
+    pub fn point_at(&self, t: f64) -> Point2D {
+        self.start + t * (self.end - self.start)
+    }
+
+    /// Returns the point where `self` and `other` cross, if they do.
+    ///
+    /// Writing the segments as `p + t·r` and `q + u·s`, the crossing is found by
+    /// solving for `t` and `u` with the 2D cross product:
+    /// \[
+    /// t = \frac{(q - p) \times s}{r \times s}, \quad
+    /// u = \frac{(q - p) \times r}{r \times s}
+    /// \]
+    /// The segments meet if both `t` and `u` lie in `[0, 1]`. Touching at an
+    /// endpoint counts as an intersection.
+    ///
+    /// Returns `None` if the segments don't meet, or are parallel (including
+    /// overlapping collinear segments, which have no single crossing point).
+    /// The parallel test is relative to the segment lengths, so it doesn't
+    /// depend on their scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Point2D, Segment2D};
+    /// let a = Segment2D::new(Point2D::new(0.0, 0.0), Point2D::new(2.0, 2.0));
+    /// let b = Segment2D::new(Point2D::new(0.0, 2.0), Point2D::new(2.0, 0.0));
+    /// assert_eq!(a.intersect(&b), Some(Point2D::new(1.0, 1.0)));
+    /// ```
+    pub fn intersect(&self, other: &Segment2D) -> Option<Point2D> {
+        let r = self.end - self.start;
+        let s = other.end - other.start;
+        let denom = r.cross(&s);
+        if denom.abs() <= EPSILON * r.mag() * s.mag() {
+            return None;
+        }
+
+        let qp = other.start - self.start;
+        let t = qp.cross(&s) / denom;
+        let u = qp.cross(&r) / denom;
+        if !(0.0..=1.0).contains(&t) || !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        Some(self.point_at(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_length() {
+        let s = Segment2D::new(Point2D::new(1.0, 2.0), Point2D::new(4.0, 6.0));
+        assert_eq!(s.length(), 5.0);
+    }
+
+    #[test]
+    fn test_point_at() {
+        let s = Segment2D::new(Point2D::new(1.0, 2.0), Point2D::new(4.0, 6.0));
+        assert_eq!(s.point_at(0.0), s.start);
+        assert_eq!(s.point_at(1.0), s.end);
+    }
+
+    #[test]
+    fn test_intersect_crossing() {
+        let a = Segment2D::new(Point2D::new(-1.0, 0.0), Point2D::new(1.0, 0.0));
+        let b = Segment2D::new(Point2D::new(0.5, -1.0), Point2D::new(0.5, 1.0));
+        assert_eq!(a.intersect(&b), Some(Point2D::new(0.5, 0.0)));
+        assert_eq!(b.intersect(&a), Some(Point2D::new(0.5, 0.0)));
+    }
+
+    #[test]
+    fn test_intersect_small_scale() {
+        let a = Segment2D::new(Point2D::new(-1e-5, 0.0), Point2D::new(1e-5, 0.0));
+        let b = Segment2D::new(Point2D::new(0.0, -1e-5), Point2D::new(0.0, 1e-5));
+        assert_eq!(a.intersect(&b), Some(Point2D::ZERO));
+    }
+
+    #[test]
+    fn test_intersect_parallel() {
+        let a = Segment2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0));
+        let b = Segment2D::new(Point2D::new(0.0, 1.0), Point2D::new(1.0, 2.0));
+        assert_eq!(a.intersect(&b), None);
+    }
+
+    #[test]
+    fn test_intersect_disjoint() {
+        let a = Segment2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0));
+        let b = Segment2D::new(Point2D::new(2.0, -1.0), Point2D::new(2.0, 1.0));
+        assert_eq!(a.intersect(&b), None);
+    }
+
+    #[test]
+    fn test_intersect_t_junction() {
+        let a = Segment2D::new(Point2D::new(-1.0, 0.0), Point2D::new(1.0, 0.0));
+        let b = Segment2D::new(Point2D::new(0.0, 0.0), Point2D::new(0.0, 1.0));
+        assert_eq!(a.intersect(&b), Some(Point2D::new(0.0, 0.0)));
+    }
+}
@@ -0,0 +1,190 @@
+//! Sphere primitive.
+//!
+//! Provides [`Sphere`], with the ray intersection and containment tests a
+//! simple ray tracer or collision system needs.
+
+use crate::{Point3D, Vec3};
+use derive_more::Constructor;
+
+/// A sphere given by its center and radius.
+///
+/// # Examples
+/// ```
+/// use lars::{Point3D, Sphere};
+/// let s = Sphere::new(Point3D::ZERO, 2.0);
+/// assert!(s.contains(&Point3D::new(1.0, 1.0, 1.0)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Constructor)]
+pub struct Sphere {
+    /// The point at the center of the sphere.
+    pub center: Point3D,
+    /// The distance from the center to the surface.
+    pub radius: f64,
+}
+
+impl Sphere {
+    /// Returns the nearest positive `t` at which the ray `origin + t·direction`
+    /// hits the sphere, or `None` if it misses.
+    ///
+    /// Substituting the ray into `|p - center|² = r²` gives a quadratic in `t`.
+    /// With `oc = origin - center`, and `b` taken as half the usual linear
+    /// coefficient:
+    /// \[
+    /// t = \frac{-b \pm \sqrt{b^2 - ac}}{a}, \quad
+    /// a = d \cdot d, \; b = oc \cdot d, \; c = oc \cdot oc - r^2
+    /// \]
+    /// A negative discriminant is a miss, and zero is a tangent, grazing hit.
+    /// If the ray starts inside the sphere, the exit point is returned.
+    ///
+    /// `t` is measured in multiples of `direction`, so it is the hit distance
+    /// when `direction` is unit length.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Point3D, Sphere, Vec3};
+    /// let s = Sphere::new(Point3D::ZERO, 1.0);
+    /// let origin = Point3D::new(0.0, 0.0, -5.0);
+    /// assert_eq!(s.intersect_ray(&origin, &Vec3::UNIT_Z), Some(4.0));
+    /// assert_eq!(s.intersect_ray(&origin, &Vec3::UNIT_X), None);
+    /// ```
+    pub fn intersect_ray(&self, origin: &Point3D, direction: &Vec3) -> Option<f64> {
+        let oc = *origin - self.center;
+        let a = direction.mag_sq();
+        let b = oc.dot(direction);
+        let c = oc.mag_sq() - self.radius * self.radius;
+        let discriminant = b * b - a * c;
+        if a == 0.0 || discriminant < 0.0 {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        [(-b - root) / a, (-b + root) / a]
+            .into_iter()
+            .find(|&t| t > 0.0)
+    }
+
+    /// Returns `true` if `p` lies inside or on the surface of the sphere.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Point3D, Sphere};
+    /// let s = Sphere::new(Point3D::ZERO, 1.0);
+    /// assert!(s.contains(&Point3D::UNIT_X));
+    /// assert!(!s.contains(&Point3D::new(1.0, 1.0, 0.0)));
+    /// ```
+    pub fn contains(&self, p: &Point3D) -> bool {
+        p.dist_sq(&self.center) <= self.radius * self.radius
+    }
+
+    /// Returns the outward **unit normal** at a point on the surface.
+    ///
+    /// `surface` is assumed to lie on the sphere; for any other point this is
+    /// the direction from the center towards it.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Point3D, Sphere, Vec3};
+    /// let s = Sphere::new(Point3D::new(1.0, 0.0, 0.0), 2.0);
+    /// assert_eq!(s.normal_at(&Point3D::new(1.0, 2.0, 0.0)), Vec3::UNIT_Y);
+    /// ```
+    pub fn normal_at(&self, surface: &Point3D) -> Vec3 {
+        (*surface - self.center).normalize()
+    }
+
+    /// Returns `true` if `self` and `other` overlap or touch, i.e. the distance
+    /// between their centers is at most the sum of their radii.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Point3D, Sphere};
+    /// let a = Sphere::new(Point3D::ZERO, 1.0);
+    /// let b = Sphere::new(Point3D::new(3.0, 0.0, 0.0), 2.0);
+    /// assert!(a.intersects_sphere(&b));
+    /// ```
+    pub fn intersects_sphere(&self, other: &Sphere) -> bool {
+        self.center.dist(&other.center) <= self.radius + other.radius
+    }
+
+    /// Returns the **signed distance** from `p` to the surface of the sphere:
+    /// positive outside, zero on the surface and negative inside.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Point3D, Sphere};
+    /// let s = Sphere::new(Point3D::ZERO, 2.0);
+    /// assert_eq!(s.distance_to(&Point3D::new(5.0, 0.0, 0.0)), 3.0);
+    /// assert_eq!(s.distance_to(&Point3D::ZERO), -2.0);
+    /// ```
+    pub fn distance_to(&self, p: &Point3D) -> f64 {
+        self.center.dist(p) - self.radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit_sphere() -> Sphere {
+        Sphere::new(Point3D::new(1.0, 2.0, 3.0), 1.0)
+    }
+
+    #[test]
+    fn test_intersect_ray_head_on() {
+        let s = unit_sphere();
+        let origin = s.center - Vec3::new(10.0, 0.0, 0.0);
+        assert_eq!(s.intersect_ray(&origin, &Vec3::UNIT_X), Some(9.0));
+        assert_eq!(s.intersect_ray(&origin, &(2.0 * Vec3::UNIT_X)), Some(4.5));
+        assert_eq!(s.intersect_ray(&origin, &-Vec3::UNIT_X), None);
+    }
+
+    #[test]
+    fn test_intersect_ray_tangent() {
+        let s = unit_sphere();
+        let origin = s.center + Vec3::new(-5.0, 1.0, 0.0);
+        assert_eq!(s.intersect_ray(&origin, &Vec3::UNIT_X), Some(5.0));
+    }
+
+    #[test]
+    fn test_intersect_ray_miss() {
+        let s = unit_sphere();
+        let origin = s.center + Vec3::new(-5.0, 1.5, 0.0);
+        assert_eq!(s.intersect_ray(&origin, &Vec3::UNIT_X), None);
+    }
+
+    #[test]
+    fn test_intersect_ray_from_inside() {
+        let s = unit_sphere();
+        assert_eq!(s.intersect_ray(&s.center, &Vec3::UNIT_Y), Some(1.0));
+    }
+
+    #[test]
+    fn test_contains_and_normal() {
+        let s = unit_sphere();
+        let surface = s.center + Vec3::new(0.6, 0.0, 0.8);
+        assert!(s.contains(&s.center));
+        assert!(s.contains(&surface));
+        assert!(!s.contains(&(s.center + Vec3::ONE)));
+        assert_eq!(s.normal_at(&surface), Vec3::new(0.6, 0.0, 0.8));
+    }
+
+    #[test]
+    fn test_intersects_sphere() {
+        let a = Sphere::new(Point3D::ZERO, 1.0);
+        let overlapping = Sphere::new(Point3D::new(0.0, 2.0, 0.0), 1.5);
+        let touching = Sphere::new(Point3D::new(0.0, 0.0, 3.0), 2.0);
+        let disjoint = Sphere::new(Point3D::new(2.0, 2.0, 2.0), 1.0);
+        assert!(a.intersects_sphere(&overlapping));
+        assert!(a.intersects_sphere(&touching));
+        assert!(touching.intersects_sphere(&a));
+        assert!(!a.intersects_sphere(&disjoint));
+        assert!(a.intersects_sphere(&a));
+    }
+
+    #[test]
+    fn test_distance_to() {
+        let s = unit_sphere();
+        assert_eq!(s.distance_to(&(s.center + Vec3::new(0.0, 3.0, 4.0))), 4.0);
+        assert_eq!(s.distance_to(&(s.center + Vec3::UNIT_Z)), 0.0);
+        assert_eq!(s.distance_to(&(s.center + 0.25 * Vec3::UNIT_X)), -0.75);
+    }
+}
@@ -0,0 +1,174 @@
+//! 2D transform utilities.
+//!
+//! Provides [`Transform2D`], a high-level description of a 2D transform as a
+//! translation, rotation and scale, which can be turned into the equivalent
+//! homogeneous [`Mat3`].
+
+use crate::{Mat2, Mat3, Point2D, Vec2};
+use derive_more::Constructor;
+
+const EPSILON: f64 = 1e-9;
+
+/// A 2D transform made up of a scale, then a rotation, then a translation.
+///
+/// # Examples
+/// ```
+/// use lars::{Point2D, Transform2D, Vec2};
+/// let t = Transform2D::new(Vec2::new(1.0, 0.0), 0.0, Vec2::new(2.0, 2.0));
+/// assert_eq!(t.transform_point(Point2D::ONE), Point2D::new(3.0, 2.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Constructor)]
+pub struct Transform2D {
+    /// Offset applied last, after scaling and rotating.
+    pub translation: Vec2,
+    /// Anticlockwise rotation in radians.
+    pub rotation: f64,
+    /// Scale factor along each axis, applied first.
+    pub scale: Vec2,
+}
+
+impl Transform2D {
+    /// The transform that leaves every point where it is.
+    pub const IDENTITY: Transform2D = Transform2D {
+        translation: Vec2::ZERO,
+        rotation: 0.0,
+        scale: Vec2::ONE,
+    };
+
+    /// Returns the equivalent 2D homogeneous matrix, `T * R * S`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Mat3, Transform2D};
+    /// assert_eq!(Transform2D::IDENTITY.to_mat3(), Mat3::IDENTITY);
+    /// ```
+    pub fn to_mat3(&self) -> Mat3 {
+        Mat3::from_trs_2d(self.translation, self.rotation, self.scale)
+    }
+
+    /// Applies the transform to a **point**: scaling, rotating, then translating it.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Point2D, Transform2D, Vec2};
+    /// let t = Transform2D::new(Vec2::new(0.0, 1.0), std::f64::consts::FRAC_PI_2, Vec2::ONE);
+    /// assert_eq!(t.transform_point(Point2D::new(1.0, 0.0)), Point2D::new(0.0, 2.0));
+    /// ```
+    pub fn transform_point(&self, p: Point2D) -> Point2D {
+        self.transform_vector(p) + self.translation
+    }
+
+    /// Applies the transform to a **direction**: scaling and rotating it, but
+    /// ignoring the translation.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Transform2D, Vec2};
+    /// let t = Transform2D::new(Vec2::new(5.0, 5.0), 0.0, Vec2::new(2.0, 1.0));
+    /// assert_eq!(t.transform_vector(Vec2::ONE), Vec2::new(2.0, 1.0));
+    /// ```
+    pub fn transform_vector(&self, v: Vec2) -> Vec2 {
+        Mat2::rotation(self.rotation) * (self.scale * v)
+    }
+
+    /// Returns the transform that undoes `self`, or `None` if it can't be
+    /// represented as a [`Transform2D`].
+    ///
+    /// A non-uniform scale followed by a rotation inverts to a shear, so `None`
+    /// is returned for that case; use `to_mat3().inverse()` instead. `None` is
+    /// also returned if either scale component is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Point2D, Transform2D, Vec2};
+    /// let t = Transform2D::new(Vec2::new(3.0, -1.0), 0.5, Vec2::new(2.0, 2.0));
+    /// let p = Point2D::new(1.0, 2.0);
+    /// assert_eq!(t.inverse().unwrap().transform_point(t.transform_point(p)), p);
+    ///
+    /// let sheared = Transform2D::new(Vec2::ZERO, 0.5, Vec2::new(2.0, 1.0));
+    /// assert_eq!(sheared.inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Transform2D> {
+        if self.scale.x == 0.0 || self.scale.y == 0.0 {
+            return None;
+        }
+        let uniform = (self.scale.x - self.scale.y).abs()
+            <= EPSILON * self.scale.x.abs().max(self.scale.y.abs());
+        if !uniform && self.rotation != 0.0 {
+            return None;
+        }
+
+        let scale = Vec2::new(1.0 / self.scale.x, 1.0 / self.scale.y);
+        let rotation = -self.rotation;
+        let translation = -(scale * (Mat2::rotation(rotation) * self.translation));
+
+        Some(Transform2D {
+            translation,
+            rotation,
+            scale,
+        })
+    }
+}
+
+/// Returns [`Transform2D::IDENTITY`].
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Transform2D {
+        Transform2D::new(Vec2::new(3.0, -2.0), 0.7, Vec2::new(2.0, 0.5))
+    }
+
+    #[test]
+    fn test_to_mat3_matches_transform_point() {
+        let t = example();
+        let m = t.to_mat3();
+        for p in [Point2D::ZERO, Point2D::new(1.0, 2.0), Point2D::new(-4.0, 0.5)] {
+            assert_eq!(m.transform_point_2d(p), t.transform_point(p));
+            assert_eq!(m.transform_vector_2d(p), t.transform_vector(p));
+        }
+    }
+
+    #[test]
+    fn test_transform_vector_ignores_translation() {
+        let t = Transform2D::new(Vec2::new(10.0, 10.0), 0.0, Vec2::ONE);
+        assert_eq!(t.transform_vector(Vec2::UNIT_X), Vec2::UNIT_X);
+        assert_eq!(t.transform_point(Point2D::ZERO), Point2D::new(10.0, 10.0));
+    }
+
+    #[test]
+    fn test_inverse_uniform_scale() {
+        let t = Transform2D::new(Vec2::new(3.0, -2.0), 0.7, Vec2::new(2.0, 2.0));
+        let inv = t.inverse().unwrap();
+        let p = Point2D::new(1.0, 2.0);
+        assert_eq!(inv.transform_point(t.transform_point(p)), p);
+        assert_eq!(t.transform_point(inv.transform_point(p)), p);
+        assert_eq!(inv.to_mat3(), t.to_mat3().inverse());
+    }
+
+    #[test]
+    fn test_inverse_no_rotation() {
+        let t = Transform2D::new(Vec2::new(3.0, -2.0), 0.0, Vec2::new(2.0, 0.5));
+        let p = Point2D::new(1.0, 2.0);
+        assert_eq!(t.inverse().unwrap().transform_point(t.transform_point(p)), p);
+    }
+
+    #[test]
+    fn test_inverse_unrepresentable() {
+        assert_eq!(example().inverse(), None);
+        let flat = Transform2D::new(Vec2::ZERO, 0.0, Vec2::new(1.0, 0.0));
+        assert_eq!(flat.inverse(), None);
+    }
+
+    #[test]
+    fn test_default() {
+        let p = Point2D::new(1.0, 2.0);
+        assert_eq!(Transform2D::default().transform_point(p), p);
+    }
+}
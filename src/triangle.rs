@@ -0,0 +1,85 @@
+//! Triangle utilities.
+//!
+//! Triangles are passed as their three [`Point3D`] corners. Their winding order
+//! decides which way the normal faces: counter-clockwise corners, seen from the
+//! front, give a normal pointing towards the viewer.
+
+use crate::{Point3D, Vec3};
+
+const EPSILON: f64 = 1e-9;
+
+/// Returns the **unit normal** of the triangle `abc`, `(b - a) × (c - a)`
+/// normalized.
+///
+/// Returns [`Vec3::ZERO`] for a degenerate triangle (collinear or repeated
+/// corners), which has no defined normal. The corners count as collinear when
+/// the sine of the angle at `a` is below epsilon, so the test doesn't depend on
+/// the size of the triangle.
+///
+/// # Examples
+/// ```
+/// use lars::{face_normal, Point3D, Vec3};
+/// let n = face_normal(&Point3D::ZERO, &Point3D::UNIT_X, &Point3D::UNIT_Y);
+/// assert_eq!(n, Vec3::UNIT_Z);
+/// ```
+pub fn face_normal(a: &Point3D, b: &Point3D, c: &Point3D) -> Vec3 {
+    let (ab, ac) = (*b - *a, *c - *a);
+    let n = ab.cross(&ac);
+    let m = n.mag();
+    if m <= EPSILON * ab.mag() * ac.mag() {
+        return Vec3::ZERO;
+    }
+    n / m
+}
+
+/// Returns the **unnormalized normal** of the triangle `abc`,
+/// `(b - a) × (c - a)`.
+///
+/// Its magnitude is twice the triangle's area, so summing these over the faces
+/// around a vertex gives an area-weighted vertex normal.
+///
+/// # Examples
+/// ```
+/// use lars::{face_normal_unnormalized, Point3D, Vec3};
+/// let a = Point3D::ZERO;
+/// let b = Point3D::new(2.0, 0.0, 0.0);
+/// let c = Point3D::new(0.0, 3.0, 0.0);
+/// assert_eq!(face_normal_unnormalized(&a, &b, &c), Vec3::new(0.0, 0.0, 6.0));
+/// ```
+pub fn face_normal_unnormalized(a: &Point3D, b: &Point3D, c: &Point3D) -> Vec3 {
+    (*b - *a).cross(&(*c - *a))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_face_normal_xy_plane() {
+        let a = Point3D::new(1.0, 1.0, 2.0);
+        let b = Point3D::new(4.0, 1.0, 2.0);
+        let c = Point3D::new(1.0, 5.0, 2.0);
+        assert_eq!(face_normal(&a, &b, &c), Vec3::UNIT_Z);
+        assert_eq!(face_normal(&a, &c, &b), -Vec3::UNIT_Z);
+        assert_eq!(face_normal_unnormalized(&a, &b, &c).mag(), 2.0 * 6.0);
+    }
+
+    #[test]
+    fn test_face_normal_degenerate() {
+        let a = Point3D::ZERO;
+        let b = Point3D::new(1.0, 1.0, 1.0);
+        let c = Point3D::new(2.0, 2.0, 2.0);
+        assert_eq!(face_normal(&a, &b, &c), Vec3::ZERO);
+        assert_eq!(face_normal(&a, &a, &b), Vec3::ZERO);
+    }
+
+    #[test]
+    fn test_face_normal_small_triangle() {
+        let a = Point3D::new(3.0, -1.0, 0.5);
+        let b = a + Point3D::new(2e-5, 0.0, 0.0);
+        let c = a + Point3D::new(0.0, 0.0, 2e-5);
+        assert_eq!(face_normal(&a, &b, &c), -Vec3::UNIT_Y);
+        assert_abs_diff_eq!(face_normal(&a, &b, &c).mag(), 1.0, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,96 @@
+//! Angle units.
+//!
+//! Provides the [`Radians`] and [`Degrees`] newtypes, so that functions taking
+//! an angle can say which unit they expect and the compiler catches a mix-up.
+//! A bare [`Scalar`] converts into [`Radians`], matching the rest of the crate.
+
+use crate::Scalar;
+
+const EPSILON: f64 = 1e-9;
+
+/// An angle in **radians**.
+///
+/// # Examples
+/// ```
+/// use lars::{Degrees, Radians, PI};
+/// assert_eq!(Radians::from(Degrees(180.0)), Radians(PI));
+/// ```
+#[derive(Clone, Copy, Debug, PartialOrd)]
+pub struct Radians(pub Scalar);
+
+/// An angle in **degrees**.
+///
+/// # Examples
+/// ```
+/// use lars::{Degrees, Radians, PI};
+/// assert_eq!(Degrees::from(Radians(PI)), Degrees(180.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialOrd)]
+pub struct Degrees(pub Scalar);
+
+/// Compares two angles, treating values within `1e-9` of each other as equal,
+/// like the vector and matrix types.
+impl PartialEq for Radians {
+    fn eq(&self, other: &Self) -> bool {
+        (self.0 - other.0).abs() < EPSILON
+    }
+}
+
+/// Compares two angles, treating values within `1e-9` of each other as equal,
+/// like the vector and matrix types.
+impl PartialEq for Degrees {
+    fn eq(&self, other: &Self) -> bool {
+        (self.0 - other.0).abs() < EPSILON
+    }
+}
+
+/// Converts an angle in degrees to radians.
+impl From<Degrees> for Radians {
+    fn from(d: Degrees) -> Self {
+        Radians(d.0.to_radians())
+    }
+}
+
+/// Converts an angle in radians to degrees.
+impl From<Radians> for Degrees {
+    fn from(r: Radians) -> Self {
+        Degrees(r.0.to_degrees())
+    }
+}
+
+/// Treats a bare [`Scalar`] as radians, the unit used throughout the crate.
+impl From<Scalar> for Radians {
+    fn from(radians: Scalar) -> Self {
+        Radians(radians)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PI;
+
+    #[test]
+    fn test_degrees_to_radians() {
+        assert_eq!(Radians::from(Degrees(180.0)), Radians(PI));
+        assert_eq!(Radians::from(Degrees(-90.0)), Radians(-PI / 2.0));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for degrees in [-45.0, 0.0, 30.0, 270.0] {
+            assert_eq!(Degrees::from(Radians::from(Degrees(degrees))), Degrees(degrees));
+        }
+    }
+
+    #[test]
+    fn test_scalar_is_radians() {
+        assert_eq!(Radians::from(1.5), Radians(1.5));
+    }
+
+    #[test]
+    fn test_eq_tolerance() {
+        assert_eq!(Radians(1.0), Radians(1.0 + 1e-12));
+        assert_ne!(Degrees(1.0), Degrees(1.0 + 1e-6));
+    }
+}
@@ -0,0 +1,87 @@
+//! Packing vectors into, and out of, flat buffers of components.
+
+use crate::Vec3;
+use std::error::Error;
+use std::fmt;
+
+/// The error returned by [`unflatten`] when the buffer length isn't a
+/// multiple of 3.
+///
+/// # Examples
+/// ```
+/// use lars::{unflatten, BufferLengthError};
+/// let err = unflatten(&[1.0, 2.0, 3.0, 4.0]).unwrap_err();
+/// assert_eq!(err, BufferLengthError { len: 4 });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BufferLengthError {
+    /// The length of the buffer that was passed in.
+    pub len: usize,
+}
+
+impl fmt::Display for BufferLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "buffer length {} is not a multiple of 3", self.len)
+    }
+}
+
+impl Error for BufferLengthError {}
+
+/// Packs `vectors` into a flat buffer of components, `[x0, y0, z0, x1, ...]`.
+///
+/// This is the layout most binary formats expect, e.g. before writing a point
+/// cloud to disk.
+///
+/// # Examples
+/// ```
+/// use lars::{flatten, Vec3};
+/// let data = flatten(&[Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)]);
+/// assert_eq!(data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+/// ```
+pub fn flatten(vectors: &[Vec3]) -> Vec<f64> {
+    vectors.iter().flat_map(Vec3::to_array).collect()
+}
+
+/// Unpacks a flat buffer of components, `[x0, y0, z0, x1, ...]`, into vectors.
+///
+/// The inverse of [`flatten`]. Returns a [`BufferLengthError`] if the length of
+/// `data` isn't a multiple of 3.
+///
+/// # Examples
+/// ```
+/// use lars::{unflatten, Vec3};
+/// let vectors = unflatten(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+/// assert_eq!(vectors, vec![Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)]);
+/// ```
+pub fn unflatten(data: &[f64]) -> Result<Vec<Vec3>, BufferLengthError> {
+    if data.len() % 3 != 0 {
+        return Err(BufferLengthError { len: data.len() });
+    }
+    Ok(data
+        .chunks_exact(3)
+        .map(|c| Vec3::new(c[0], c[1], c[2]))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let vectors = [
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-4.5, 0.0, 6.25),
+            Vec3::ZERO,
+        ];
+        let data = flatten(&vectors);
+        assert_eq!(data.len(), 9);
+        assert_eq!(unflatten(&data).unwrap(), vectors);
+    }
+
+    #[test]
+    fn test_unflatten_bad_length() {
+        assert_eq!(unflatten(&[1.0, 2.0]), Err(BufferLengthError { len: 2 }));
+        assert_eq!(unflatten(&[]), Ok(Vec::new()));
+    }
+}
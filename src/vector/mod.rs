@@ -1,5 +1,9 @@
+mod buffer;
+mod parse;
 mod vector2;
 mod vector3;
 
+pub use buffer::{flatten, unflatten, BufferLengthError};
+pub use parse::ParseVecError;
 pub use vector2::{Point2D, Vec2};
-pub use vector3::{Colour, Point3D, Vec3};
+pub use vector3::{Colour, HashableVec3, Point3D, Vec3};
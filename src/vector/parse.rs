@@ -0,0 +1,76 @@
+//! String parsing shared by the vector types.
+
+use std::error::Error;
+use std::fmt;
+use std::num::ParseFloatError;
+
+/// The error returned when parsing a vector from a string fails.
+///
+/// # Examples
+/// ```
+/// use lars::{ParseVecError, Vec3};
+/// let err = "1, 2".parse::<Vec3>().unwrap_err();
+/// assert_eq!(err, ParseVecError::WrongComponentCount { expected: 3, found: 2 });
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseVecError {
+    /// The string had the wrong number of components for the vector type.
+    WrongComponentCount {
+        /// The number of components the vector type needs.
+        expected: usize,
+        /// The number of components in the string.
+        found: usize,
+    },
+    /// One of the components could not be parsed as a float.
+    InvalidComponent(ParseFloatError),
+}
+
+impl fmt::Display for ParseVecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseVecError::WrongComponentCount { expected, found } => {
+                write!(f, "expected {expected} components, found {found}")
+            }
+            ParseVecError::InvalidComponent(e) => write!(f, "invalid component: {e}"),
+        }
+    }
+}
+
+impl Error for ParseVecError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseVecError::InvalidComponent(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Parses exactly `N` float components from a string like `"1, 2, 3"`, `"(1,2,3)"` or `"1 2 3"`.
+///
+/// Components are separated by commas, or by whitespace if there are no commas,
+/// and may be wrapped in a single pair of parentheses.
+pub(crate) fn parse_components<const N: usize>(s: &str) -> Result<[f64; N], ParseVecError> {
+    let s = s.trim();
+    let s = s
+        .strip_prefix('(')
+        .and_then(|inner| inner.strip_suffix(')'))
+        .unwrap_or(s);
+
+    let parts: Vec<&str> = if s.contains(',') {
+        s.split(',').map(str::trim).collect()
+    } else {
+        s.split_whitespace().collect()
+    };
+    if parts.len() != N {
+        return Err(ParseVecError::WrongComponentCount {
+            expected: N,
+            found: parts.len(),
+        });
+    }
+
+    let mut components = [0.0; N];
+    for (c, part) in components.iter_mut().zip(parts) {
+        *c = part.parse().map_err(ParseVecError::InvalidComponent)?;
+    }
+    Ok(components)
+}
@@ -5,10 +5,12 @@
 //!
 //! It supports vector addition, subtraction, scaling, dot and cross products, and normalization.
 
-use crate::Mat2;
+use super::parse::parse_components;
+use crate::{approx_eq, lerp, Mat2, ParseVecError, Vec3};
 use derive_more::{Add, Constructor, Div, Mul, Neg, Sub};
 use std::fmt;
 use std::ops::Mul;
+use std::str::FromStr;
 
 /// A 2-dimensional vector type
 ///
@@ -22,6 +24,7 @@ use std::ops::Mul;
 /// let a = Vec2::new(3.0, 4.0);
 /// assert_eq!(a.mag(), 5.0);
 /// ```
+#[repr(C)]
 #[derive(Add, Sub, Div, Mul, Neg, Clone, Copy, Debug, PartialOrd, Constructor)]
 pub struct Vec2 {
     /// X component of the vector.
@@ -32,6 +35,12 @@ pub struct Vec2 {
 
 const EPSILON: f64 = 1e-9;
 
+/// Compares two [`Vec2`]s component by component, treating components within
+/// `1e-9` of each other as equal, the same as the matrix types.
+///
+/// This tolerance means `==` survives small rounding errors, but it is not
+/// transitive. Use [`exact_eq`](Vec2::exact_eq) for exact comparison, or
+/// [`abs_diff_eq`](Vec2::abs_diff_eq) to choose the tolerance.
 impl PartialEq for Vec2 {
     fn eq(&self, other: &Self) -> bool {
         (self.x - other.x).abs() < EPSILON && (self.y - other.y).abs() < EPSILON
@@ -48,6 +57,37 @@ impl Vec2 {
     /// A Unit Vector in Y (0.0, 1.0, 0.0)
     pub const UNIT_Y: Vec2 = Vec2 { x: 0.0, y: 1.0 };
 
+    /// Returns the unit vector `(cos θ, sin θ)` pointing at `radians` anticlockwise
+    /// from the positive X axis.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// assert_eq!(Vec2::from_angle(0.0), Vec2::UNIT_X);
+    /// assert_eq!(Vec2::from_angle(std::f64::consts::FRAC_PI_2), Vec2::UNIT_Y);
+    /// ```
+    pub fn from_angle(radians: f64) -> Vec2 {
+        let (sin, cos) = radians.sin_cos();
+        Vec2::new(cos, sin)
+    }
+
+    /// Returns the angle of the vector in radians, measured anticlockwise from the
+    /// positive X axis, in the range `(-π, π]`.
+    ///
+    /// This is the inverse of [`from_angle`](Vec2::from_angle).
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let v = Vec2::new(0.0, 2.0);
+    /// assert_eq!(v.angle(), std::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn angle(&self) -> f64 {
+        self.y.atan2(self.x)
+    }
+
     /// Returns the **magnitude** (length) of the vector.
     ///
     /// # Examples
@@ -77,6 +117,24 @@ impl Vec2 {
         (self.x * other.x) + (self.y * other.y)
     }
 
+    /// Returns the **cosine of the angle** between `self` and another [`Vec2`].
+    ///
+    /// This is `dot / (|self| * |other|)` without the `acos`. The result is
+    /// clamped to `[-1, 1]` to absorb rounding error. Returns `NaN` if either
+    /// vector has zero length.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let a = Vec2::new(3.0, 0.0);
+    /// let b = Vec2::new(-2.0, 0.0);
+    /// assert_eq!(a.cos_angle(&b), -1.0);
+    /// ```
+    pub fn cos_angle(&self, other: &Vec2) -> f64 {
+        (self.dot(other) / (self.mag() * other.mag())).clamp(-1.0, 1.0)
+    }
+
     /// Returns the **scalar 2D cross product** between `self` and another [`Vec2`].
     ///
     /// Unlike the 3D cross product, the 2D version returns a scalar equal to
@@ -94,6 +152,58 @@ impl Vec2 {
         self.x * other.y - self.y * other.x
     }
 
+    /// Returns the **3D cross product** of `self` and another [`Vec2`], treating
+    /// both as lying in the XY plane (`z = 0`).
+    ///
+    /// The result always points along the Z axis, and its `z` component is the
+    /// scalar 2D [`cross`](Vec2::cross).
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::{Vec2, Vec3};
+    /// let a = Vec2::new(1.0, 0.0);
+    /// let b = Vec2::new(0.0, 1.0);
+    /// assert_eq!(a.cross_vec3(&b), Vec3::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn cross_vec3(&self, other: &Vec2) -> Vec3 {
+        Vec3::new(0.0, 0.0, self.cross(other))
+    }
+
+    /// Extends the vector into 3D, using `z` as the new component.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::{Vec2, Vec3};
+    /// let v = Vec2::new(1.0, 2.0);
+    /// assert_eq!(v.extend(3.0), Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn extend(&self, z: f64) -> Vec3 {
+        Vec3::new(self.x, self.y, z)
+    }
+
+    /// Returns the **outer product** `self ⊗ other` as a [`Mat2`].
+    ///
+    /// Element `(i, j)` of the result is `self[i] * other[j]`.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::{Mat2, Vec2};
+    /// let a = Vec2::new(1.0, 2.0);
+    /// let b = Vec2::new(3.0, 4.0);
+    /// assert_eq!(a.outer(&b), Mat2::new(3.0, 4.0, 6.0, 8.0));
+    /// ```
+    pub fn outer(&self, other: &Vec2) -> Mat2 {
+        Mat2::new(
+            self.x * other.x,
+            self.x * other.y,
+            self.y * other.x,
+            self.y * other.y,
+        )
+    }
+
     /// Applies a function `f` to each component (`x`, `y`) of the vector.
     ///
     /// # Examples
@@ -134,6 +244,371 @@ impl Vec2 {
         self.map(|i| i / m)
     }
 
+    /// Returns the components of the vector as an array.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let v = Vec2::new(1.0, 2.0);
+    /// assert_eq!(v.to_array(), [1.0, 2.0]);
+    /// ```
+    pub fn to_array(&self) -> [f64; 2] {
+        [self.x, self.y]
+    }
+
+    /// Views the components of the vector as a slice, without copying.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let v = Vec2::new(1.0, 2.0);
+    /// assert_eq!(v.as_slice(), &[1.0, 2.0]);
+    /// ```
+    pub fn as_slice(&self) -> &[f64] {
+        // SAFETY: `Vec2` is `#[repr(C)]` and made up of 2 consecutive `f64` fields,
+        // so it has the same layout as `[f64; 2]`.
+        unsafe { std::slice::from_raw_parts(self as *const Vec2 as *const f64, 2) }
+    }
+
+    /// Returns the sum of the vector's components.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let v = Vec2::new(3.0, -1.0);
+    /// assert_eq!(v.component_sum(), 2.0);
+    /// ```
+    pub fn component_sum(&self) -> f64 {
+        self.x + self.y
+    }
+
+    /// Returns the smallest of the vector's components.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let v = Vec2::new(3.0, -1.0);
+    /// assert_eq!(v.component_min(), -1.0);
+    /// ```
+    pub fn component_min(&self) -> f64 {
+        self.x.min(self.y)
+    }
+
+    /// Returns the largest of the vector's components.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let v = Vec2::new(3.0, -1.0);
+    /// assert_eq!(v.component_max(), 3.0);
+    /// ```
+    pub fn component_max(&self) -> f64 {
+        self.x.max(self.y)
+    }
+
+    /// Raises each component of the vector to the power `exp`.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let v = Vec2::new(2.0, 3.0);
+    /// assert_eq!(v.powf(2.0), Vec2::new(4.0, 9.0));
+    /// ```
+    pub fn powf(&self, exp: f64) -> Vec2 {
+        self.map(|c| c.powf(exp))
+    }
+
+    /// Returns the square root of each component of the vector.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let v = Vec2::new(4.0, 9.0);
+    /// assert_eq!(v.sqrt(), Vec2::new(2.0, 3.0));
+    /// ```
+    pub fn sqrt(&self) -> Vec2 {
+        self.map(f64::sqrt)
+    }
+
+    /// Returns `true` if the magnitude of the vector is within a small epsilon
+    /// (`1e-9`) of zero, i.e. it has no usable direction.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// assert!(Vec2::ZERO.approx_zero());
+    /// assert!(!Vec2::UNIT_X.approx_zero());
+    /// ```
+    pub fn approx_zero(&self) -> bool {
+        self.approx_zero_eps(EPSILON)
+    }
+
+    /// Returns `true` if the magnitude of the vector is below `eps`.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let v = Vec2::new(1e-6, 0.0);
+    /// assert!(v.approx_zero_eps(1e-3));
+    /// assert!(!v.approx_zero_eps(1e-9));
+    /// ```
+    pub fn approx_zero_eps(&self, eps: f64) -> bool {
+        self.mag_sq() < eps * eps
+    }
+
+    /// Returns a **normalized** version of the vector, or `None` if its magnitude
+    /// is too close to zero to normalize.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// assert_eq!(Vec2::new(0.0, 2.0).try_normalize(), Some(Vec2::UNIT_Y));
+    /// assert_eq!(Vec2::ZERO.try_normalize(), None);
+    /// ```
+    pub fn try_normalize(&self) -> Option<Vec2> {
+        if self.approx_zero() {
+            return None;
+        }
+        let m = self.mag();
+        Some(self.map(|i| i / m))
+    }
+
+    /// Returns a **normalized** version of the vector, or `fallback` if its
+    /// magnitude is too close to zero to normalize.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// assert_eq!(Vec2::ZERO.normalize_or(Vec2::UNIT_X), Vec2::UNIT_X);
+    /// ```
+    pub fn normalize_or(&self, fallback: Vec2) -> Vec2 {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Returns a **normalized** version of the vector, or [`Vec2::ZERO`] if its
+    /// magnitude is too close to zero to normalize.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// assert_eq!(Vec2::ZERO.normalize_or_zero(), Vec2::ZERO);
+    /// ```
+    pub fn normalize_or_zero(&self) -> Vec2 {
+        self.normalize_or(Vec2::ZERO)
+    }
+
+    /// Returns the **normalized** vector together with the original magnitude,
+    /// taking the square root only once.
+    ///
+    /// Handy for splitting an offset into a direction and a distance. If the
+    /// magnitude is too close to zero to normalize, the direction is
+    /// [`Vec2::ZERO`].
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// assert_eq!(Vec2::new(3.0, 4.0).normalize_and_length(), (Vec2::new(0.6, 0.8), 5.0));
+    /// assert_eq!(Vec2::ZERO.normalize_and_length(), (Vec2::ZERO, 0.0));
+    /// ```
+    pub fn normalize_and_length(&self) -> (Vec2, f64) {
+        let m = self.mag();
+        if m < EPSILON {
+            return (Vec2::ZERO, m);
+        }
+        (self.map(|i| i / m), m)
+    }
+
+    /// Returns a copy of the vector with `x` replaced.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let v = Vec2::new(1.0, 1.0);
+    /// assert_eq!(v.with_x(5.0), Vec2::new(5.0, 1.0));
+    /// ```
+    pub fn with_x(&self, x: f64) -> Vec2 {
+        Vec2 { x, y: self.y }
+    }
+
+    /// Returns a copy of the vector with `y` replaced.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let v = Vec2::new(1.0, 1.0);
+    /// assert_eq!(v.with_y(5.0), Vec2::new(1.0, 5.0));
+    /// ```
+    pub fn with_y(&self, y: f64) -> Vec2 {
+        Vec2 { x: self.x, y }
+    }
+
+    /// **Linearly interpolates** between `self` and `other` by `t`.
+    ///
+    /// `t = 0.0` returns `self` and `t = 1.0` returns `other`. Values of `t`
+    /// outside `[0, 1]` extrapolate along the same line.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let a = Vec2::ZERO;
+    /// let b = Vec2::new(2.0, 4.0);
+    /// assert_eq!(a.lerp(&b, 0.5), Vec2::new(1.0, 2.0));
+    /// ```
+    pub fn lerp(&self, other: &Vec2, t: f64) -> Vec2 {
+        Vec2 {
+            x: lerp(self.x, other.x, t),
+            y: lerp(self.y, other.y, t),
+        }
+    }
+
+    /// **Reflects** the vector off a surface with the given `normal`.
+    ///
+    /// Computed as `v - 2(v · n)n`. `normal` should be unit length.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let v = Vec2::new(1.0, -1.0);
+    /// assert_eq!(v.reflect(&Vec2::UNIT_Y), Vec2::new(1.0, 1.0));
+    /// ```
+    pub fn reflect(&self, normal: &Vec2) -> Vec2 {
+        *self - 2.0 * self.dot(normal) * *normal
+    }
+
+    /// **Bounces** the vector off a surface with the given `normal`, scaling the
+    /// part of the vector along the normal by `restitution`.
+    ///
+    /// A `restitution` of `1.0` is a perfect bounce (the same as
+    /// [`reflect`](Vec2::reflect)), and `0.0` removes the normal part entirely so
+    /// the vector slides along the surface. `normal` should be unit length.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let v = Vec2::new(1.0, -2.0);
+    /// assert_eq!(v.bounce(&Vec2::UNIT_Y, 0.5), Vec2::new(1.0, 1.0));
+    /// ```
+    pub fn bounce(&self, normal: &Vec2, restitution: f64) -> Vec2 {
+        let normal_part = self.dot(normal) * *normal;
+        *self - (1.0 + restitution) * normal_part
+    }
+
+    /// Snaps each component to the nearest multiple of `cell`, e.g. to line a
+    /// position up with a square tile grid.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let v = Vec2::new(1.3, 2.7);
+    /// assert_eq!(v.snap_to_grid(0.5), Vec2::new(1.5, 2.5));
+    /// ```
+    pub fn snap_to_grid(&self, cell: f64) -> Vec2 {
+        self.map(|c| (c / cell).round() * cell)
+    }
+
+    /// Snaps each component to the nearest multiple of the matching component of
+    /// `cell`, for grids whose cells aren't square.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let v = Vec2::new(1.3, 2.7);
+    /// assert_eq!(v.snap_to_grid_per_axis(Vec2::new(1.0, 2.0)), Vec2::new(1.0, 2.0));
+    /// ```
+    pub fn snap_to_grid_per_axis(&self, cell: Vec2) -> Vec2 {
+        Vec2 {
+            x: (self.x / cell.x).round() * cell.x,
+            y: (self.y / cell.y).round() * cell.y,
+        }
+    }
+
+    /// Rounds each component **down** and converts it to an integer, e.g. to
+    /// find the tile containing a point.
+    ///
+    /// The conversion uses `as i64`, so components outside the `i64` range
+    /// saturate to `i64::MIN`/`i64::MAX` and `NaN` becomes `0`.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// assert_eq!(Vec2::new(1.7, -0.2).floor_to_i64(), [1, -1]);
+    /// ```
+    pub fn floor_to_i64(&self) -> [i64; 2] {
+        [self.x.floor() as i64, self.y.floor() as i64]
+    }
+
+    /// Rounds each component to the **nearest** integer (halfway cases away from
+    /// zero) and converts it with `as i64`.
+    ///
+    /// Out-of-range components saturate and `NaN` becomes `0`, as with
+    /// [`floor_to_i64`](Vec2::floor_to_i64).
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// assert_eq!(Vec2::new(1.7, -2.5).round_to_i64(), [2, -3]);
+    /// ```
+    pub fn round_to_i64(&self) -> [i64; 2] {
+        [self.x.round() as i64, self.y.round() as i64]
+    }
+
+    /// Returns `true` if every component of `self` is **exactly** equal to the
+    /// matching component of `other`, with no tolerance.
+    ///
+    /// This uses float `==` per component, so `0.0` equals `-0.0` and `NaN`
+    /// never equals anything. Unlike `==` on [`Vec2`], vectors one rounding
+    /// step apart are not equal.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let a = Vec2::new(0.1 + 0.2, 0.0);
+    /// let b = Vec2::new(0.3, 0.0);
+    /// assert_eq!(a, b);
+    /// assert!(!a.exact_eq(&b));
+    /// ```
+    pub fn exact_eq(&self, other: &Vec2) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+
+    /// Returns `true` if every component of `self` is within `eps` of the
+    /// matching component of `other`.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// let a = Vec2::new(1.0, 2.0);
+    /// assert!(a.abs_diff_eq(&Vec2::new(1.05, 2.0), 0.1));
+    /// assert!(!a.abs_diff_eq(&Vec2::new(1.5, 2.0), 0.1));
+    /// ```
+    pub fn abs_diff_eq(&self, other: &Vec2, eps: f64) -> bool {
+        approx_eq(self.x, other.x, eps) && approx_eq(self.y, other.y, eps)
+    }
+
     // All functions below this point are variations of the above functions
 
     ///  Returns the **magnitude**  of the vector, squared.
@@ -148,6 +623,32 @@ impl Vec2 {
     pub fn mag_sq(&self) -> f64 {
         self.x * self.x + self.y * self.y
     }
+
+    /// Returns the **infinity norm** (L∞) of the vector, its largest absolute
+    /// component.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// assert_eq!(Vec2::new(1.0, -4.0).norm_inf(), 4.0);
+    /// ```
+    pub fn norm_inf(&self) -> f64 {
+        self.x.abs().max(self.y.abs())
+    }
+
+    /// Returns the **L1 norm** of the vector, the sum of its absolute
+    /// components.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec2;
+    /// assert_eq!(Vec2::new(1.0, -4.0).norm_l1(), 5.0);
+    /// ```
+    pub fn norm_l1(&self) -> f64 {
+        self.x.abs() + self.y.abs()
+    }
 }
 
 /// Implements **scalar multiplication** for `f64 * Vec2`.
@@ -238,6 +739,148 @@ impl Point2D {
     pub fn dist_sq(&self, other: &Point2D) -> f64 {
         (*self - *other).mag_sq().abs()
     }
+
+    /// Alias for [`dist`](Point2D::dist), matching the naming used by other math crates.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Point2D;
+    /// let a = Point2D::new(1.0, 0.0);
+    /// let b = Point2D::new(4.0, 4.0);
+    /// assert_eq!(a.distance(&b), 5.0);
+    /// ```
+    pub fn distance(&self, other: &Point2D) -> f64 {
+        self.dist(other)
+    }
+
+    /// Alias for [`dist_sq`](Point2D::dist_sq), matching the naming used by other math crates.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Point2D;
+    /// let a = Point2D::new(1.0, 0.0);
+    /// let b = Point2D::new(4.0, 4.0);
+    /// assert_eq!(a.distance_squared(&b), 25.0);
+    /// ```
+    pub fn distance_squared(&self, other: &Point2D) -> f64 {
+        self.dist_sq(other)
+    }
+
+    /// Returns the **Manhattan** (L1) distance between `self` and `other`, the sum
+    /// of the absolute differences of their components.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Point2D;
+    /// let a = Point2D::new(1.0, 2.0);
+    /// let b = Point2D::new(4.0, 0.0);
+    /// assert_eq!(a.distance_manhattan(&b), 5.0);
+    /// ```
+    pub fn distance_manhattan(&self, other: &Point2D) -> f64 {
+        (*self - *other).map(f64::abs).component_sum()
+    }
+
+    /// Returns the **Chebyshev** (L∞) distance between `self` and `other`, the
+    /// largest absolute difference of their components.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Point2D;
+    /// let a = Point2D::new(1.0, 2.0);
+    /// let b = Point2D::new(4.0, 0.0);
+    /// assert_eq!(a.distance_chebyshev(&b), 3.0);
+    /// ```
+    pub fn distance_chebyshev(&self, other: &Point2D) -> f64 {
+        (*self - *other).map(f64::abs).component_max()
+    }
+
+    /// Moves `self` towards `target` by at most `max_delta`.
+    ///
+    /// Returns `target` if it is within `max_delta`, otherwise takes a step of
+    /// exactly `max_delta` along the line towards it.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Point2D;
+    /// let a = Point2D::ZERO;
+    /// let b = Point2D::new(0.0, 10.0);
+    /// assert_eq!(a.move_towards(&b, 1.0), Point2D::new(0.0, 1.0));
+    /// assert_eq!(a.move_towards(&b, 20.0), b);
+    /// ```
+    pub fn move_towards(&self, target: &Point2D, max_delta: f64) -> Point2D {
+        let delta = *target - *self;
+        let distance = delta.mag();
+        if distance <= max_delta || distance == 0.0 {
+            return *target;
+        }
+        *self + delta / distance * max_delta
+    }
+
+    /// Rotates `self` anticlockwise by `radians` around `pivot`.
+    ///
+    /// The point is moved so the pivot is at the origin, rotated, then moved
+    /// back, without building a matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Point2D;
+    /// let p = Point2D::new(2.0, 1.0);
+    /// let pivot = Point2D::new(1.0, 1.0);
+    /// assert_eq!(p.rotate_around(&pivot, std::f64::consts::FRAC_PI_2), Point2D::new(1.0, 2.0));
+    /// ```
+    pub fn rotate_around(&self, pivot: &Point2D, radians: f64) -> Point2D {
+        let (sin, cos) = radians.sin_cos();
+        let d = *self - *pivot;
+        *pivot + Vec2::new(d.x * cos - d.y * sin, d.x * sin + d.y * cos)
+    }
+
+    /// Returns the point on the infinite line through `a` and `b` closest to `self`.
+    ///
+    /// If `a` and `b` are the same point the line is undefined, and `a` is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Point2D;
+    /// let a = Point2D::new(0.0, 0.0);
+    /// let b = Point2D::new(1.0, 0.0);
+    /// let p = Point2D::new(5.0, 3.0);
+    /// assert_eq!(p.closest_on_line(&a, &b), Point2D::new(5.0, 0.0));
+    /// ```
+    pub fn closest_on_line(&self, a: &Point2D, b: &Point2D) -> Point2D {
+        match self.projection_param(a, b) {
+            Some(t) => *a + t * (*b - *a),
+            None => *a,
+        }
+    }
+
+    /// Returns the point on the segment from `a` to `b` closest to `self`.
+    ///
+    /// If `a` and `b` are the same point, `a` is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Point2D;
+    /// let a = Point2D::new(0.0, 0.0);
+    /// let b = Point2D::new(1.0, 0.0);
+    /// let p = Point2D::new(5.0, 3.0);
+    /// assert_eq!(p.closest_on_segment(&a, &b), b);
+    /// ```
+    pub fn closest_on_segment(&self, a: &Point2D, b: &Point2D) -> Point2D {
+        match self.projection_param(a, b) {
+            Some(t) => *a + t.clamp(0.0, 1.0) * (*b - *a),
+            None => *a,
+        }
+    }
+
+    /// Returns how far along `a → b` the projection of `self` lands, or `None`
+    /// if `a` and `b` coincide.
+    fn projection_param(&self, a: &Point2D, b: &Point2D) -> Option<f64> {
+        if a == b {
+            return None;
+        }
+        let ab = *b - *a;
+        Some((*self - *a).dot(&ab) / ab.mag_sq())
+    }
 }
 
 /// displays the vector in the form (X, Y)
@@ -247,6 +890,25 @@ impl fmt::Display for Vec2 {
     }
 }
 
+/// Parses a vector from a string such as `"1, 2"`, `"(1, 2)"` or `"1 2"`.
+///
+/// This accepts the format written by the [`Display`](fmt::Display) impl.
+///
+/// # Examples
+/// ```
+/// use lars::Vec2;
+/// let v: Vec2 = "(1.5, -2)".parse().unwrap();
+/// assert_eq!(v, Vec2::new(1.5, -2.0));
+/// ```
+impl FromStr for Vec2 {
+    type Err = ParseVecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse_components(s)?;
+        Ok(Vec2 { x, y })
+    }
+}
+
 /// Returns (0.0, 0.0)
 impl Default for Vec2 {
     fn default() -> Self {
@@ -254,9 +916,70 @@ impl Default for Vec2 {
     }
 }
 
+/// Compares two [`Vec2`]s element by element, within an absolute tolerance.
+///
+/// Available with the `approx` feature.
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Vec2 {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        EPSILON
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon)
+            && self.y.abs_diff_eq(&other.y, epsilon)
+    }
+}
+
+/// Compares two [`Vec2`]s element by element, within a relative tolerance.
+///
+/// Available with the `approx` feature.
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Vec2 {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+    }
+}
+
+/// Converts from a `mint` vector. Available with the `mint` feature.
+#[cfg(feature = "mint")]
+impl From<mint::Vector2<f64>> for Vec2 {
+    fn from(v: mint::Vector2<f64>) -> Self {
+        Vec2::new(v.x, v.y)
+    }
+}
+
+/// Converts to a `mint` vector. Available with the `mint` feature.
+#[cfg(feature = "mint")]
+impl From<Vec2> for mint::Vector2<f64> {
+    fn from(v: Vec2) -> Self {
+        mint::Vector2 { x: v.x, y: v.y }
+    }
+}
+
+/// Returns [`Vec2::ZERO`]. Available with the `num-traits` feature.
+#[cfg(feature = "num-traits")]
+impl num_traits::Zero for Vec2 {
+    fn zero() -> Self {
+        Vec2::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Vec2::ZERO
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use approx::assert_abs_diff_eq;
     #[test]
     fn test_add() {
         let v1 = Vec2::ZERO;
@@ -307,7 +1030,7 @@ mod tests {
     fn test_normalize() {
         let v = Vec2::new(3.0, 4.0);
         let n = v.normalize();
-        assert!((n.mag() - 1.0).abs() < 1e-10);
+        assert_abs_diff_eq!(n.mag(), 1.0, epsilon = 1e-10);
     }
 
     #[test]
@@ -342,5 +1065,279 @@ mod tests {
         let b = Vec2::UNIT_Y;
         assert_eq!(a.dot(&b), 0.0);
     }
-}
 
+    #[test]
+    fn test_to_array() {
+        let v = Vec2::new(1.0, 2.0);
+        assert_eq!(v.to_array(), [v.x, v.y]);
+    }
+
+    #[test]
+    fn test_as_slice() {
+        let v = Vec2::new(1.0, 2.0);
+        assert_eq!(v.as_slice()[0], v.x);
+        assert_eq!(v.as_slice()[1], v.y);
+    }
+
+    #[test]
+    fn test_cross_vec3() {
+        let a = Vec2::new(2.0, 1.0);
+        let b = Vec2::new(-1.0, 3.0);
+        assert_eq!(a.cross_vec3(&b), a.extend(0.0).cross(&b.extend(0.0)));
+    }
+
+    #[test]
+    fn test_component_reductions() {
+        let v = Vec2::new(-3.0, 5.0);
+        assert_eq!(v.component_sum(), 2.0);
+        assert_eq!(v.component_min(), -3.0);
+        assert_eq!(v.component_max(), 5.0);
+    }
+
+    #[test]
+    fn test_outer_trace() {
+        let a = Vec2::new(1.5, -2.0);
+        let b = Vec2::new(3.0, 4.0);
+        assert_eq!(a.outer(&b).trace(), a.dot(&b));
+    }
+
+    #[test]
+    fn test_sqrt_powf() {
+        let v = Vec2::new(4.0, 9.0);
+        assert_eq!(v.sqrt(), Vec2::new(2.0, 3.0));
+        assert_eq!(v.sqrt().powf(2.0), v);
+    }
+
+    #[test]
+    fn test_distance_aliases() {
+        let a = Point2D::new(1.0, 0.0);
+        let b = Point2D::new(4.0, 4.0);
+        assert_eq!(a.distance(&b), a.dist(&b));
+        assert_eq!(a.distance_squared(&b), a.dist_sq(&b));
+    }
+
+    #[test]
+    fn test_normalize_or() {
+        let v = Vec2::new(3.0, 4.0);
+        assert_eq!(v.normalize_or(Vec2::UNIT_X), v.normalize());
+        assert_eq!(v.normalize_or_zero(), v.normalize());
+        assert_eq!(Vec2::ZERO.normalize_or(Vec2::UNIT_Y), Vec2::UNIT_Y);
+        assert_eq!(Vec2::ZERO.normalize_or_zero(), Vec2::ZERO);
+        assert_eq!(Vec2::ZERO.try_normalize(), None);
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn test_approx() {
+        use approx::{assert_abs_diff_ne, assert_relative_eq};
+        let a = Vec2::new(1.0, 2.0);
+        let b = Vec2::new(1.0, 2.0 + 1e-12);
+        assert_abs_diff_eq!(a, b);
+        assert_relative_eq!(a, b);
+        assert_abs_diff_ne!(a, a * 2.0);
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn test_mint_round_trip() {
+        let v = Vec2::new(1.0, 2.0);
+        let m: mint::Vector2<f64> = v.into();
+        assert_eq!((m.x, m.y), (1.0, 2.0));
+        assert_eq!(Vec2::from(m), v);
+    }
+
+    #[test]
+    fn test_move_towards_step() {
+        let a = Point2D::new(1.0, 1.0);
+        let b = Point2D::new(4.0, 5.0);
+        assert_eq!(a.move_towards(&b, 2.5), Point2D::new(2.5, 3.0));
+    }
+
+    #[test]
+    fn test_move_towards_arrive() {
+        let a = Point2D::new(1.0, 1.0);
+        let b = Point2D::new(4.0, 5.0);
+        assert_eq!(a.move_towards(&b, 5.0), b);
+        assert_eq!(b.move_towards(&b, 0.0), b);
+    }
+
+    #[test]
+    fn test_from_angle() {
+        use std::f64::consts::PI;
+        assert_eq!(Vec2::from_angle(0.0), Vec2::new(1.0, 0.0));
+        assert_eq!(Vec2::from_angle(PI / 2.0), Vec2::new(0.0, 1.0));
+        assert_eq!(Vec2::from_angle(PI), Vec2::new(-1.0, 0.0));
+    }
+
+    #[test]
+    fn test_angle_round_trip() {
+        for v in [Vec2::new(3.0, 4.0), Vec2::new(-1.0, 2.0), Vec2::new(-2.0, -5.0)] {
+            assert_eq!(Vec2::from_angle(v.angle()).normalize(), v.normalize());
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        let expected = Vec2::new(1.0, -2.5);
+        assert_eq!("1,-2.5".parse::<Vec2>(), Ok(expected));
+        assert_eq!(" (1, -2.5) ".parse::<Vec2>(), Ok(expected));
+        assert_eq!(expected.to_string().parse::<Vec2>(), Ok(expected));
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        assert_eq!(
+            "1, 2, 3".parse::<Vec2>(),
+            Err(ParseVecError::WrongComponentCount { expected: 2, found: 3 })
+        );
+        assert!(matches!(
+            "x, 2".parse::<Vec2>(),
+            Err(ParseVecError::InvalidComponent(_))
+        ));
+    }
+
+    #[test]
+    fn test_closest_on_segment_middle() {
+        let a = Point2D::new(0.0, 0.0);
+        let b = Point2D::new(4.0, 4.0);
+        let p = Point2D::new(0.0, 4.0);
+        assert_eq!(p.closest_on_segment(&a, &b), Point2D::new(2.0, 2.0));
+        assert_eq!(p.closest_on_line(&a, &b), Point2D::new(2.0, 2.0));
+    }
+
+    #[test]
+    fn test_closest_on_segment_clamps() {
+        let a = Point2D::new(0.0, 0.0);
+        let b = Point2D::new(4.0, 0.0);
+        let before = Point2D::new(-3.0, 1.0);
+        let after = Point2D::new(7.0, -2.0);
+        assert_eq!(before.closest_on_segment(&a, &b), a);
+        assert_eq!(after.closest_on_segment(&a, &b), b);
+        assert_eq!(before.closest_on_line(&a, &b), Point2D::new(-3.0, 0.0));
+        assert_eq!(after.closest_on_line(&a, &b), Point2D::new(7.0, 0.0));
+    }
+
+    #[test]
+    fn test_closest_on_degenerate_segment() {
+        let a = Point2D::new(1.0, 1.0);
+        let p = Point2D::new(5.0, 3.0);
+        assert_eq!(p.closest_on_segment(&a, &a), a);
+        assert_eq!(p.closest_on_line(&a, &a), a);
+    }
+
+    #[test]
+    fn test_with_components() {
+        let v = Vec2::new(1.0, 2.0);
+        assert_eq!(v.with_x(5.0), Vec2::new(5.0, 2.0));
+        assert_eq!(v.with_y(5.0).y, 5.0);
+        assert_eq!(v.with_y(5.0).x, 1.0);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let a = Vec2::new(1.0, 2.0);
+        let b = Vec2::new(3.0, 6.0);
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+        assert_eq!(a.lerp(&b, 0.25), Vec2::new(1.5, 3.0));
+    }
+
+    #[test]
+    fn test_bounce() {
+        let v = Vec2::new(2.0, -3.0);
+        let n = Vec2::new(1.0, 1.0).normalize();
+        assert_eq!(v.bounce(&n, 1.0), v.reflect(&n));
+        assert_abs_diff_eq!(v.bounce(&n, 0.0).dot(&n), 0.0, epsilon = 1e-10);
+        assert_eq!(Vec2::new(2.0, -3.0).bounce(&Vec2::UNIT_Y, 0.0), Vec2::new(2.0, 0.0));
+    }
+
+    #[test]
+    fn test_snap_to_grid() {
+        let v = Vec2::new(1.3, 2.7);
+        assert_eq!(v.snap_to_grid(1.0), Vec2::new(1.0, 3.0));
+        assert_eq!(Vec2::new(-1.3, 7.4).snap_to_grid(2.0), Vec2::new(-2.0, 8.0));
+        assert_eq!(v.snap_to_grid_per_axis(Vec2::new(1.0, 0.5)), Vec2::new(1.0, 2.5));
+    }
+
+    #[test]
+    fn test_distance_metrics() {
+        let a = Point2D::new(1.0, -2.0);
+        let b = Point2D::new(-2.0, 2.0);
+        assert_eq!(a.dist(&b), 5.0);
+        assert_eq!(a.distance_manhattan(&b), 7.0);
+        assert_eq!(a.distance_chebyshev(&b), 4.0);
+    }
+
+    #[test]
+    fn test_cos_angle() {
+        let a = Vec2::new(2.0, 0.0);
+        assert_eq!(a.cos_angle(&Vec2::new(0.0, -4.0)), 0.0);
+        assert_eq!(a.cos_angle(&Vec2::new(7.0, 0.0)), 1.0);
+        let b = Vec2::new(0.1, 0.7);
+        assert!(b.cos_angle(&(b * 3.0)) <= 1.0);
+    }
+
+    #[test]
+    fn test_floor_and_round_to_i64() {
+        let v = Vec2::new(2.6, -2.6);
+        assert_eq!(v.floor_to_i64(), [2, -3]);
+        assert_eq!(v.round_to_i64(), [3, -3]);
+    }
+
+    #[test]
+    fn test_approx_zero() {
+        assert!(Vec2::ZERO.approx_zero());
+        let tiny = Vec2::new(1e-7, -1e-7);
+        assert!(!tiny.approx_zero());
+        assert!(tiny.approx_zero_eps(1e-6));
+        assert!(!tiny.approx_zero_eps(1e-8));
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits() {
+        use num_traits::Zero;
+        assert!(Vec2::zero().is_zero());
+        assert!(!Vec2::ONE.is_zero());
+    }
+
+    #[test]
+    fn test_rotate_around() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+        let pivot = Point2D::new(-1.0, 2.0);
+        let p = Point2D::new(2.0, 2.0);
+        assert_eq!(p.rotate_around(&pivot, FRAC_PI_2), Point2D::new(-1.0, 5.0));
+        assert_eq!(p.rotate_around(&pivot, PI), Point2D::new(-4.0, 2.0));
+        assert_eq!(pivot.rotate_around(&pivot, 1.0), pivot);
+        assert_eq!(p.rotate_around(&Point2D::ZERO, 0.7), Mat2::rotation(0.7) * p);
+    }
+
+    #[test]
+    fn test_exact_eq_vs_abs_diff_eq() {
+        let a = Vec2::new(1.0, 2.0);
+        let b = Vec2::new(f64::from_bits(1.0f64.to_bits() + 1), 2.0);
+        assert!(a.exact_eq(&a));
+        assert!(!a.exact_eq(&b));
+        assert!(a.abs_diff_eq(&b, 1e-12));
+        assert!(!a.abs_diff_eq(&b, 0.0));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_normalize_and_length() {
+        let v = Vec2::new(-2.0, 7.5);
+        let (dir, len) = v.normalize_and_length();
+        assert_eq!(dir, v.normalize());
+        assert_eq!(len, v.mag());
+        assert_eq!(Vec2::ZERO.normalize_and_length(), (Vec2::ZERO, 0.0));
+    }
+
+    #[test]
+    fn test_norms() {
+        let v = Vec2::new(-3.0, 4.0);
+        assert_eq!(v.norm_inf(), 4.0);
+        assert_eq!(v.norm_l1(), 7.0);
+        assert_eq!(v.mag(), 5.0);
+        assert_eq!(Point2D::ZERO.distance_chebyshev(&v), v.norm_inf());
+    }
+}
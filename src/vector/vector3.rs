@@ -6,8 +6,11 @@
 
 use derive_more::{Add, Constructor, Div, Mul, Neg, Sub};
 use std::fmt;
-use std::ops::Mul;
-use crate::Mat3;
+use std::hash::{Hash, Hasher};
+use std::ops::{Div, Mul};
+use std::str::FromStr;
+use super::parse::parse_components;
+use crate::{approx_eq, lerp, Mat3, ParseVecError};
 
 /// A 3-dimensional vector type.
 ///
@@ -24,6 +27,7 @@ use crate::Mat3;
 /// let cross = a.cross(&b); // Vec3 { x: 0.0, y: 0.0, z: 1.0 }
 /// let dot = a.dot(&b); // 0.0
 /// ```
+#[repr(C)]
 #[derive(Add, Sub, Mul, Div, Neg, Clone, Copy, Debug, PartialOrd, Constructor)]
 pub struct Vec3 {
     /// X component of the vector.
@@ -36,6 +40,12 @@ pub struct Vec3 {
 
 const EPSILON: f64 = 1e-9;
 
+/// Compares two [`Vec3`]s component by component, treating components within
+/// `1e-9` of each other as equal, the same as the matrix types.
+///
+/// This tolerance means `==` survives small rounding errors, but it is not
+/// transitive. Use [`exact_eq`](Vec3::exact_eq) for exact comparison, or
+/// [`abs_diff_eq`](Vec3::abs_diff_eq) to choose the tolerance.
 impl PartialEq for Vec3 {
     fn eq(&self, other: &Self) -> bool {
         (self.x - other.x).abs() < EPSILON &&
@@ -102,6 +112,23 @@ impl Vec3 {
         (self.x * other.x) + (self.y * other.y) + (self.z * other.z)
     }
 
+    /// Returns the **cosine of the angle** between `self` and another [`Vec3`].
+    ///
+    /// This is `dot / (|self| * |other|)` without the `acos`, which is all a
+    /// lighting term usually needs. The result is clamped to `[-1, 1]` to absorb
+    /// rounding error. Returns `NaN` if either vector has zero length.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let a = Vec3::new(2.0, 0.0, 0.0);
+    /// let b = Vec3::new(1.0, 1.0, 0.0);
+    /// assert!((a.cos_angle(&b) - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-12);
+    /// ```
+    pub fn cos_angle(&self, other: &Vec3) -> f64 {
+        (self.dot(other) / (self.mag() * other.mag())).clamp(-1.0, 1.0)
+    }
+
     /// Returns the **cross product** between `self` and another [`Vec3`].
     ///
     /// The cross product is perpendicular to both vectors.
@@ -120,6 +147,63 @@ impl Vec3 {
         Vec3 { x, y, z }
     }
 
+    /// Returns the **normalized cross product** of `self` and `other`, a unit
+    /// vector perpendicular to both, e.g. a face normal from two edges.
+    ///
+    /// If the inputs are parallel (or either is zero) the cross product has zero
+    /// magnitude and every component of the result is NaN. Use
+    /// [`try_cross_normalized`](Vec3::try_cross_normalized) to handle that case.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let n = Vec3::new(2.0, 0.0, 0.0).cross_normalized(&Vec3::new(0.0, 3.0, 0.0));
+    /// assert_eq!(n, Vec3::UNIT_Z);
+    /// ```
+    pub fn cross_normalized(&self, other: &Vec3) -> Vec3 {
+        self.cross(other).normalize()
+    }
+
+    /// Returns the **normalized cross product** of `self` and `other`, or `None`
+    /// if the inputs are parallel and the cross product is too close to zero.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// assert_eq!(Vec3::UNIT_Y.try_cross_normalized(&Vec3::UNIT_Z), Some(Vec3::UNIT_X));
+    /// assert_eq!(Vec3::UNIT_Y.try_cross_normalized(&Vec3::new(0.0, -2.0, 0.0)), None);
+    /// ```
+    pub fn try_cross_normalized(&self, other: &Vec3) -> Option<Vec3> {
+        self.cross(other).try_normalize()
+    }
+
+    /// Returns the **outer product** `self ⊗ other` as a [`Mat3`].
+    ///
+    /// Element `(i, j)` of the result is `self[i] * other[j]`. It is the
+    /// complement of the [`dot`](Vec3::dot) (inner) product, and is handy for
+    /// building matrices such as the reflection `I - 2nnᵀ`.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::{Mat3, Vec3};
+    /// let n = Vec3::UNIT_Z;
+    /// let reflect = Mat3::IDENTITY - 2.0 * n.outer(&n);
+    /// assert_eq!(reflect * Vec3::ONE, Vec3::new(1.0, 1.0, -1.0));
+    /// ```
+    pub fn outer(&self, other: &Vec3) -> Mat3 {
+        Mat3 {
+            a: self.x * other.x,
+            b: self.x * other.y,
+            c: self.x * other.z,
+            d: self.y * other.x,
+            e: self.y * other.y,
+            f: self.y * other.z,
+            g: self.z * other.x,
+            h: self.z * other.y,
+            i: self.z * other.z,
+        }
+    }
+
     /// Applies a function `f` to each component (`x`, `y`, and `z`) of the vector.
     ///
     /// # Examples
@@ -161,203 +245,1472 @@ impl Vec3 {
         self.map(|i| i / m)
     }
 
-    // All functions below this point are variations of the above functions
-
-    /// Returns the **magnitude**  of the vector, squared.
+    /// Returns the components of the vector as an array.
     ///
     /// # Examples
     /// ```
-    ///  use lars::Vec3;
-    /// let v = Vec3::new(3.0, 4.0, 0.0);
-    /// assert_eq!(v.mag_sq(), 25.0);
+    ///
+    /// use lars::Vec3;
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.to_array(), [1.0, 2.0, 3.0]);
     /// ```
-    pub fn mag_sq(&self) -> f64 {
-        self.x * self.x + self.y * self.y + self.z * self.z
+    pub fn to_array(&self) -> [f64; 3] {
+        [self.x, self.y, self.z]
     }
-}
 
-/// Implements scalar multiplication of a vector by a float (`f64`).
-///
-/// This enables `f64 * Vec3` syntax.
-///
-/// # Examples
-/// ```
-///  use lars::Vec3;
-/// let v = Vec3::new(1.0, 2.0, 3.0);
-/// let scaled = 2.0 * v;
-/// assert_eq!(scaled, Vec3::new(2.0, 4.0, 6.0));
-/// ```
-impl Mul<Vec3> for f64 {
-    type Output = Vec3;
-    fn mul(self, vector: Vec3) -> Vec3 {
-        Vec3 {
-            x: self * vector.x,
-            y: self * vector.y,
-            z: self * vector.z,
-        }
+    /// Views the components of the vector as a slice, without copying.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// use lars::Vec3;
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.as_slice(), &[1.0, 2.0, 3.0]);
+    /// ```
+    pub fn as_slice(&self) -> &[f64] {
+        // SAFETY: `Vec3` is `#[repr(C)]` and made up of 3 consecutive `f64` fields,
+        // so it has the same layout as `[f64; 3]`.
+        unsafe { std::slice::from_raw_parts(self as *const Vec3 as *const f64, 3) }
     }
-}
-
-/// displays the vector in the form (X, Y, Z)
 
-impl fmt::Display for Vec3 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}, {}, {})", self.x, self.y, self.z)
-    }
-}
+    /// Returns two unit vectors that are perpendicular to `self` and to each other.
+    ///
+    /// Together with `self.normalize()` they form a right-handed orthonormal basis
+    /// `(self, a, b)`, which is useful for building a coordinate frame around a
+    /// surface normal. To stay numerically stable, `self` is crossed with whichever
+    /// axis it is least aligned with.
+    ///
+    /// `self` should be non-zero; a zero vector has no meaningful perpendiculars.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let (a, b) = Vec3::UNIT_Z.orthonormal_basis();
+    /// assert_eq!(a.dot(&Vec3::UNIT_Z), 0.0);
+    /// assert_eq!(b.dot(&Vec3::UNIT_Z), 0.0);
+    /// assert_eq!(a.dot(&b), 0.0);
+    /// ```
+    pub fn orthonormal_basis(&self) -> (Vec3, Vec3) {
+        let n = self.normalize();
+        let (ax, ay, az) = (n.x.abs(), n.y.abs(), n.z.abs());
+        let axis = if ax <= ay && ax <= az {
+            Vec3::UNIT_X
+        } else if ay <= az {
+            Vec3::UNIT_Y
+        } else {
+            Vec3::UNIT_Z
+        };
 
-/// Returns (0.0, 0.0, 0.0)
-impl Default for Vec3 {
-    fn default() -> Self {
-        Self::ZERO
+        let a = axis.cross(&n).normalize();
+        let b = n.cross(&a);
+        (a, b)
     }
-}
 
-/// Implements **component-wise multiplication** between two [`Vec3`]s.
-///
-/// This is useful for operations such as color blending or per-component scaling.
-///
-/// # Examples
-/// ```
-///  use lars::Vec3;
-/// let a = Vec3::new(1.0, 2.0, 3.0);
-/// let b = Vec3::new(2.0, 0.5, 4.0);
-/// assert_eq!(a * b, Vec3::new(2.0, 1.0, 12.0));
-/// ```
-impl Mul<Vec3> for Vec3 {
-    type Output = Vec3;
-    fn mul(self, vector: Vec3) -> Vec3 {
+    /// **Linearly interpolates** between `self` and `other` by `t`.
+    ///
+    /// `t = 0.0` returns `self` and `t = 1.0` returns `other`. Values of `t`
+    /// outside `[0, 1]` extrapolate along the same line.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let a = Vec3::ZERO;
+    /// let b = Vec3::new(2.0, 4.0, 6.0);
+    /// assert_eq!(a.lerp(&b, 0.5), Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn lerp(&self, other: &Vec3, t: f64) -> Vec3 {
         Vec3 {
-            x: self.x * vector.x,
-            y: self.y * vector.y,
-            z: self.z * vector.z,
+            x: lerp(self.x, other.x, t),
+            y: lerp(self.y, other.y, t),
+            z: lerp(self.z, other.z, t),
         }
     }
-}
-
-impl Mul<Mat3> for Vec3 {
-    type Output = Vec3;
 
-    fn mul(self, rhs: Mat3) -> Vec3 {
-        Vec3 {
-            x: self.x * rhs.a + self.y * rhs.d + self.z * rhs.g,
-            y: self.x * rhs.b + self.y * rhs.e + self.z * rhs.h,
-            z: self.x * rhs.c + self.y * rhs.f + self.z * rhs.i,
-        }
+    /// Linearly interpolates between `self` and `other`, with `t` clamped to `[0, 1]`.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let a = Vec3::ZERO;
+    /// let b = Vec3::ONE;
+    /// assert_eq!(a.lerp_clamped(&b, 2.0), b);
+    /// ```
+    pub fn lerp_clamped(&self, other: &Vec3, t: f64) -> Vec3 {
+        self.lerp(other, t.clamp(0.0, 1.0))
     }
-}
-
-/// Represents an RGB color with values between `0.0` and `1.0`.
-/// Will eventually contain support for conversions with the image crate
-///
-/// Alias for [`Vec3`].
-pub type Colour = Vec3;
 
-/// Represents a 3D point in space.
-///
-/// Alias for [`Vec3`].
-pub type Point3D = Vec3;
-impl Point3D {
-    /// Finds the unsigned distance between `self` and another 3D point `Other`.
+    /// **Normalized lerp**: linearly interpolates between the directions
+    /// `self` and `other`, then normalizes the result.
     ///
-    /// #examples
+    /// This is a cheap stand-in for spherical interpolation (slerp) between
+    /// unit vectors. It follows the same arc, but the angle doesn't change at a
+    /// constant rate: it moves fastest near `t = 0.5`, and the difference grows
+    /// as the angle between the inputs gets wider. Returns [`Vec3::ZERO`] if
+    /// the interpolated vector has zero length, e.g. halfway between opposite
+    /// directions.
     ///
+    /// # Examples
     /// ```
-    /// use lars::{Point3D};
-    /// let a = Point3D::new(1.0, 0.0, 0.0);
-    /// let b = Point3D::new(0.0, 0.0, 0.0);
-    /// assert_eq!(a.dist(&b), 1.0)
+    ///  use lars::Vec3;
+    /// let v = Vec3::UNIT_X.nlerp(&Vec3::UNIT_Y, 0.5);
+    /// assert_eq!(v, Vec3::new(1.0, 1.0, 0.0).normalize());
     /// ```
-    pub fn dist(&self, other: &Point3D) -> f64 {
-        (*self - *other).mag().abs()
+    pub fn nlerp(&self, other: &Vec3, t: f64) -> Vec3 {
+        self.lerp(other, t).normalize_or_zero()
     }
-    /// Finds the unsigned distance between `self` and another 3D point `Other`, squared
+
+    /// Interpolates between `self` and `other` using **smoothstep** easing.
     ///
-    /// #examples
+    /// `t` is clamped to `[0, 1]` and eased by `3t² - 2t³`, so the motion starts
+    /// and ends with zero velocity.
     ///
+    /// # Examples
     /// ```
-    ///
-    /// use lars::Point3D;
-    /// let a = Point3D::new(2.0, 0.0, 0.0);
-    /// let b = Point3D::new(0.0, 0.0, 0.0);
-    /// assert_eq!(a.dist_sq(&b), 4.0)
-    ///
+    ///  use lars::Vec3;
+    /// let a = Vec3::ZERO;
+    /// let b = Vec3::ONE;
+    /// assert_eq!(a.smoothstep(&b, 0.5), Vec3::new(0.5, 0.5, 0.5));
     /// ```
-    pub fn dist_sq(&self, other: &Point3D) -> f64 {
-        (*self - *other).mag_sq().abs()
+    pub fn smoothstep(&self, other: &Vec3, t: f64) -> Vec3 {
+        let t = t.clamp(0.0, 1.0);
+        self.lerp(other, t * t * (3.0 - 2.0 * t))
     }
-}
-
-// TESTS
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[test]
-    fn test_add() {
-        let v1 = Vec3::ZERO;
-        let v2 = Vec3::ONE;
-        assert_eq!(v1 + v2, Vec3::ONE);
-    }
-    #[test]
-    fn test_mag() {
-        let v = Vec3::new(1.0, 2.0, 2.0);
-        assert_eq!(v.mag(), 3.0);
-    }
-    #[test]
-    fn test_mag_sq() {
-        let v = Vec3::new(1.0, 2.0, 2.0);
-        assert_eq!(v.mag_sq(), 9.0);
-    }
-    #[test]
-    fn test_dist() {
-        let a = Vec3::new(1.0, 2.0, 3.0);
-        let b = Vec3::new(1.0, 0.0, 3.0);
-        assert_eq!(a.dist(&b), 2.0);
-    }
-    #[test]
-    fn test_dist_sq() {
-        let a = Point3D::new(1.0, 2.0, 3.0);
-        let b = Point3D::new(1.0, 0.0, 3.0);
-        assert_eq!(a.dist_sq(&b), 4.0);
+    /// Returns the sum of the vector's components.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(1.0, -2.0, 4.0);
+    /// assert_eq!(v.component_sum(), 3.0);
+    /// ```
+    pub fn component_sum(&self) -> f64 {
+        self.x + self.y + self.z
     }
-    #[test]
-    fn test_dot_product() {
-        let a = Point3D::new(1.0, 2.0, 3.0);
-        let b = Point3D::new(4.0, -5.0, 6.0);
-        assert_eq!(a.dot(&b), 12.0);
+
+    /// Returns the smallest of the vector's components.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(1.0, -2.0, 4.0);
+    /// assert_eq!(v.component_min(), -2.0);
+    /// ```
+    pub fn component_min(&self) -> f64 {
+        self.x.min(self.y).min(self.z)
     }
 
-    #[test]
-    fn test_cross_product() {
-        let a = Vec3::new(1.0, 0.0, 0.0);
-        let b = Vec3::new(0.0, 1.0, 0.0);
-        assert_eq!(a.cross(&b), Vec3::new(0.0, 0.0, 1.0));
+    /// Returns the largest of the vector's components.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(1.0, -2.0, 4.0);
+    /// assert_eq!(v.component_max(), 4.0);
+    /// ```
+    pub fn component_max(&self) -> f64 {
+        self.x.max(self.y).max(self.z)
     }
 
-    #[test]
-    fn test_normalize() {
-        let v = Vec3::new(3.0, 4.0, 0.0);
-        let n = v.normalize();
-        assert!((n.mag() - 1.0).abs() < 1e-10);
+    /// Returns the **component-wise reciprocal** of the vector, `1 / c` for each component.
+    ///
+    /// Zero components become infinite; use [`try_recip`](Vec3::try_recip) to
+    /// catch them instead.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(2.0, 4.0, 0.5);
+    /// assert_eq!(v.recip(), Vec3::new(0.5, 0.25, 2.0));
+    /// ```
+    pub fn recip(&self) -> Vec3 {
+        self.map(|c| 1.0 / c)
     }
 
-    #[test]
-    fn test_scalar_mul() {
-        let v = Vec3::new(1.0, 2.0, 3.0);
-        assert_eq!(2.0 * v, Vec3::new(2.0, 4.0, 6.0));
+    /// Returns the component-wise reciprocal of the vector, or `None` if any
+    /// component is zero.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(2.0, 0.0, 0.5);
+    /// assert_eq!(v.try_recip(), None);
+    /// ```
+    pub fn try_recip(&self) -> Option<Vec3> {
+        if self.x == 0.0 || self.y == 0.0 || self.z == 0.0 {
+            return None;
+        }
+        Some(self.recip())
     }
 
-    #[test]
-    fn test_component_mul() {
-        let a = Vec3::new(2.0, 3.0, 4.0);
-        let b = Vec3::new(1.0, 2.0, 3.0);
-        assert_eq!(a * b, Vec3::new(2.0, 6.0, 12.0));
+    /// Divides `self` by `other` component-wise, or returns `None` if any
+    /// component of `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(6.0, 8.0, 10.0);
+    /// assert_eq!(v.checked_div(&Vec3::new(2.0, 4.0, 5.0)), Some(Vec3::new(3.0, 2.0, 2.0)));
+    /// assert_eq!(v.checked_div(&Vec3::new(2.0, 0.0, 5.0)), None);
+    /// ```
+    pub fn checked_div(&self, other: &Vec3) -> Option<Vec3> {
+        if other.x == 0.0 || other.y == 0.0 || other.z == 0.0 {
+            return None;
+        }
+        Some(*self / *other)
     }
 
-    #[test]
-    fn test_default() {
-        let v = Vec3::default();
+    /// Raises each component of the vector to the power `exp`.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.powf(2.0), Vec3::new(1.0, 4.0, 9.0));
+    /// ```
+    pub fn powf(&self, exp: f64) -> Vec3 {
+        self.map(|c| c.powf(exp))
+    }
+
+    /// Returns the square root of each component of the vector.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(4.0, 9.0, 16.0);
+    /// assert_eq!(v.sqrt(), Vec3::new(2.0, 3.0, 4.0));
+    /// ```
+    pub fn sqrt(&self) -> Vec3 {
+        self.map(f64::sqrt)
+    }
+
+    /// Returns `true` if the magnitude of the vector is within a small epsilon
+    /// (`1e-9`) of zero, i.e. it has no usable direction.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// assert!(Vec3::ZERO.approx_zero());
+    /// assert!(!Vec3::UNIT_X.approx_zero());
+    /// ```
+    pub fn approx_zero(&self) -> bool {
+        self.approx_zero_eps(EPSILON)
+    }
+
+    /// Returns `true` if the magnitude of the vector is below `eps`.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(1e-6, 0.0, 0.0);
+    /// assert!(v.approx_zero_eps(1e-3));
+    /// assert!(!v.approx_zero_eps(1e-9));
+    /// ```
+    pub fn approx_zero_eps(&self, eps: f64) -> bool {
+        self.mag_sq() < eps * eps
+    }
+
+    /// Returns a **normalized** version of the vector, or `None` if its magnitude
+    /// is too close to zero to normalize.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// assert_eq!(Vec3::new(0.0, 2.0, 0.0).try_normalize(), Some(Vec3::UNIT_Y));
+    /// assert_eq!(Vec3::ZERO.try_normalize(), None);
+    /// ```
+    pub fn try_normalize(&self) -> Option<Vec3> {
+        if self.approx_zero() {
+            return None;
+        }
+        let m = self.mag();
+        Some(self.map(|i| i / m))
+    }
+
+    /// Returns a **normalized** version of the vector, or `fallback` if its
+    /// magnitude is too close to zero to normalize.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// assert_eq!(Vec3::ZERO.normalize_or(Vec3::UNIT_Z), Vec3::UNIT_Z);
+    /// ```
+    pub fn normalize_or(&self, fallback: Vec3) -> Vec3 {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Returns a **normalized** version of the vector, or [`Vec3::ZERO`] if its
+    /// magnitude is too close to zero to normalize.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// assert_eq!(Vec3::ZERO.normalize_or_zero(), Vec3::ZERO);
+    /// ```
+    pub fn normalize_or_zero(&self) -> Vec3 {
+        self.normalize_or(Vec3::ZERO)
+    }
+
+    /// Returns the **normalized** vector together with the original magnitude,
+    /// taking the square root only once.
+    ///
+    /// Handy for splitting an offset into a direction and a distance. If the
+    /// magnitude is too close to zero to normalize, the direction is
+    /// [`Vec3::ZERO`].
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// assert_eq!(Vec3::new(0.0, 3.0, 4.0).normalize_and_length(), (Vec3::new(0.0, 0.6, 0.8), 5.0));
+    /// assert_eq!(Vec3::ZERO.normalize_and_length(), (Vec3::ZERO, 0.0));
+    /// ```
+    pub fn normalize_and_length(&self) -> (Vec3, f64) {
+        let m = self.mag();
+        if m < EPSILON {
+            return (Vec3::ZERO, m);
+        }
+        (self.map(|i| i / m), m)
+    }
+
+    /// Clamps every component of the vector to the range `[lo, hi]`.
+    ///
+    /// # Panics
+    /// Panics if `lo > hi`.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(-2.0, 0.5, 3.0);
+    /// assert_eq!(v.clamp_scalar(-1.0, 1.0), Vec3::new(-1.0, 0.5, 1.0));
+    /// ```
+    pub fn clamp_scalar(&self, lo: f64, hi: f64) -> Vec3 {
+        self.map(|c| c.clamp(lo, hi))
+    }
+
+    /// Clamps every component of the vector to `[0, 1]`, e.g. to keep a
+    /// [`Colour`] in range before output.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Colour;
+    /// let c = Colour::new(1.5, 0.5, -0.2);
+    /// assert_eq!(c.saturate(), Colour::new(1.0, 0.5, 0.0));
+    /// ```
+    pub fn saturate(&self) -> Vec3 {
+        self.clamp_scalar(0.0, 1.0)
+    }
+
+    /// Scales the vector so its length lies in `[min, max]`, keeping its
+    /// direction. Vectors already in range are returned unchanged.
+    ///
+    /// A zero vector has no direction to extend along, so it is returned as
+    /// zero even if `min > 0`.
+    ///
+    /// # Panics
+    /// Panics if `min > max`.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// assert_eq!(Vec3::new(0.0, 10.0, 0.0).clamp_length(1.0, 2.0), Vec3::new(0.0, 2.0, 0.0));
+    /// assert_eq!(Vec3::new(0.0, 0.5, 0.0).clamp_length(1.0, 2.0), Vec3::new(0.0, 1.0, 0.0));
+    /// ```
+    pub fn clamp_length(&self, min: f64, max: f64) -> Vec3 {
+        let m = self.mag();
+        let clamped = m.clamp(min, max);
+        if m == 0.0 || clamped == m {
+            return *self;
+        }
+        *self * (clamped / m)
+    }
+
+    /// Lengthens the vector to at least `min`, keeping its direction, e.g. to
+    /// stop a repulsion force from vanishing.
+    ///
+    /// A zero vector is returned as zero, as with
+    /// [`clamp_length`](Vec3::clamp_length).
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// assert_eq!(Vec3::new(0.0, 0.0, 0.5).clamp_length_min(2.0), Vec3::new(0.0, 0.0, 2.0));
+    /// assert_eq!(Vec3::ZERO.clamp_length_min(2.0), Vec3::ZERO);
+    /// ```
+    pub fn clamp_length_min(&self, min: f64) -> Vec3 {
+        self.clamp_length(min, f64::INFINITY)
+    }
+
+    /// Shortens the vector to at most `max`, keeping its direction.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// assert_eq!(Vec3::new(3.0, 4.0, 0.0).clamp_length_max(1.0), Vec3::new(0.6, 0.8, 0.0));
+    /// ```
+    pub fn clamp_length_max(&self, max: f64) -> Vec3 {
+        self.clamp_length(0.0, max)
+    }
+
+    /// Returns the index (`0`, `1` or `2` for `x`, `y` or `z`) of the largest component.
+    ///
+    /// Ties resolve to the lowest index.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(1.0, 5.0, 3.0);
+    /// assert_eq!(v.max_axis(), 1);
+    /// ```
+    pub fn max_axis(&self) -> usize {
+        if self.x >= self.y && self.x >= self.z {
+            0
+        } else if self.y >= self.z {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Returns the index (`0`, `1` or `2` for `x`, `y` or `z`) of the smallest component.
+    ///
+    /// Ties resolve to the lowest index.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(1.0, 5.0, -3.0);
+    /// assert_eq!(v.min_axis(), 2);
+    /// ```
+    pub fn min_axis(&self) -> usize {
+        if self.x <= self.y && self.x <= self.z {
+            0
+        } else if self.y <= self.z {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Builds a vector from **spherical coordinates**.
+    ///
+    /// Uses the physics convention:
+    /// - `r` is the distance from the origin.
+    /// - `theta` is the polar angle in radians, measured down from the `+Z` axis (`0` to `π`).
+    /// - `phi` is the azimuthal angle in radians, measured anticlockwise from `+X` in the XY plane.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// use std::f64::consts::FRAC_PI_2;
+    /// assert_eq!(Vec3::from_spherical(1.0, 0.0, 0.0), Vec3::UNIT_Z);
+    /// assert_eq!(Vec3::from_spherical(2.0, FRAC_PI_2, FRAC_PI_2), Vec3::new(0.0, 2.0, 0.0));
+    /// ```
+    pub fn from_spherical(r: f64, theta: f64, phi: f64) -> Vec3 {
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        Vec3::new(
+            r * sin_theta * cos_phi,
+            r * sin_theta * sin_phi,
+            r * cos_theta,
+        )
+    }
+
+    /// Converts the vector to **spherical coordinates** `(r, theta, phi)`.
+    ///
+    /// See [`from_spherical`](Vec3::from_spherical) for the angle conventions.
+    /// `phi` is in the range `(-π, π]`.
+    ///
+    /// On the Z axis the azimuth is undefined and `phi` is `0.0`; for the zero
+    /// vector both angles are `0.0`.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// use std::f64::consts::FRAC_PI_2;
+    /// assert_eq!(Vec3::new(0.0, 3.0, 0.0).to_spherical(), (3.0, FRAC_PI_2, FRAC_PI_2));
+    /// ```
+    pub fn to_spherical(&self) -> (f64, f64, f64) {
+        let r = self.mag();
+        if r == 0.0 {
+            return (0.0, 0.0, 0.0);
+        }
+
+        let theta = (self.z / r).clamp(-1.0, 1.0).acos();
+        let phi = self.y.atan2(self.x);
+        (r, theta, phi)
+    }
+
+    /// Returns the unit direction for a **latitude** and **longitude**, in
+    /// radians, with `+Y` up:
+    ///
+    /// - `lat` is the angle above the XZ plane, from `-π/2` (the `-Y` pole) to
+    ///   `π/2` (the `+Y` pole).
+    /// - `lon` is the angle anticlockwise about `+Y`, seen from above, measured
+    ///   from `+Z`, so `lon = π/2` points along `+X`.
+    ///
+    /// Unlike [`from_spherical`](Vec3::from_spherical), which is `+Z` up, this
+    /// suits sky domes and globes in a y-up world.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// use std::f64::consts::FRAC_PI_2;
+    /// assert_eq!(Vec3::from_lat_lon(0.0, 0.0), Vec3::UNIT_Z);
+    /// assert_eq!(Vec3::from_lat_lon(0.0, FRAC_PI_2), Vec3::UNIT_X);
+    /// assert_eq!(Vec3::from_lat_lon(FRAC_PI_2, 0.0), Vec3::UNIT_Y);
+    /// ```
+    pub fn from_lat_lon(lat_radians: f64, lon_radians: f64) -> Vec3 {
+        let (sin_lat, cos_lat) = lat_radians.sin_cos();
+        let (sin_lon, cos_lon) = lon_radians.sin_cos();
+        Vec3::new(cos_lat * sin_lon, sin_lat, cos_lat * cos_lon)
+    }
+
+    /// Converts the direction of the vector to `(lat, lon)` in radians.
+    ///
+    /// See [`from_lat_lon`](Vec3::from_lat_lon) for the conventions. `lon` is in
+    /// the range `(-π, π]`. The length of the vector is ignored.
+    ///
+    /// At the poles the longitude is undefined and `0.0` is returned; for the
+    /// zero vector both angles are `0.0`.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// use std::f64::consts::FRAC_PI_2;
+    /// assert_eq!(Vec3::new(2.0, 0.0, 0.0).to_lat_lon(), (0.0, FRAC_PI_2));
+    /// ```
+    pub fn to_lat_lon(&self) -> (f64, f64) {
+        let r = self.mag();
+        if r == 0.0 {
+            return (0.0, 0.0);
+        }
+
+        let lat = (self.y / r).clamp(-1.0, 1.0).asin();
+        let lon = if self.x == 0.0 && self.z == 0.0 {
+            0.0
+        } else {
+            self.x.atan2(self.z)
+        };
+        (lat, lon)
+    }
+
+    /// Performs the **perspective divide** of homogeneous coordinates
+    /// `(x, y, z, w)`, with `self` holding `xyz`: returns `(x/w, y/w, z/w)`.
+    ///
+    /// This is the last step of projecting clip-space coordinates. A point at
+    /// infinity (`w == 0`) can't be divided, so `self` is returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let clip = Vec3::new(2.0, 4.0, 6.0);
+    /// assert_eq!(clip.homogeneous_divide(2.0), Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn homogeneous_divide(&self, w: f64) -> Vec3 {
+        if w == 0.0 {
+            return *self;
+        }
+        *self / w
+    }
+
+    /// Orients `self` (usually a normal) to face against an incident direction.
+    ///
+    /// Returns `self` if `reference.dot(incident) < 0`, otherwise `-self`. This
+    /// matches GLSL's `faceforward`.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let n = Vec3::UNIT_Y;
+    /// let incident = Vec3::new(0.0, 1.0, 0.0);
+    /// assert_eq!(n.faceforward(&incident, &n), -n);
+    /// ```
+    pub fn faceforward(&self, incident: &Vec3, reference: &Vec3) -> Vec3 {
+        if reference.dot(incident) < 0.0 {
+            *self
+        } else {
+            -*self
+        }
+    }
+
+    /// Rotates the vector anticlockwise by `radians` about `axis`, using
+    /// Rodrigues' rotation formula:
+    /// \[
+    /// v' = v\cos\theta + (k \times v)\sin\theta + k(k \cdot v)(1 - \cos\theta)
+    /// \]
+    /// where `k` is the normalized axis. This is the one-shot equivalent of
+    /// `Mat3::from_axis_angle(axis, radians) * v`.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::UNIT_X.rotate_around_axis(Vec3::UNIT_Z, std::f64::consts::FRAC_PI_2);
+    /// assert_eq!(v, Vec3::UNIT_Y);
+    /// ```
+    pub fn rotate_around_axis(&self, axis: Vec3, radians: f64) -> Vec3 {
+        let k = axis.normalize();
+        let (sin, cos) = radians.sin_cos();
+        *self * cos + k.cross(self) * sin + k * (k.dot(self) * (1.0 - cos))
+    }
+
+    /// Returns a copy of the vector with `x` replaced.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(1.0, 1.0, 1.0);
+    /// assert_eq!(v.with_x(5.0), Vec3::new(5.0, 1.0, 1.0));
+    /// ```
+    pub fn with_x(&self, x: f64) -> Vec3 {
+        Vec3 { x, y: self.y, z: self.z }
+    }
+
+    /// Returns a copy of the vector with `y` replaced.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(1.0, 1.0, 1.0);
+    /// assert_eq!(v.with_y(5.0), Vec3::new(1.0, 5.0, 1.0));
+    /// ```
+    pub fn with_y(&self, y: f64) -> Vec3 {
+        Vec3 { x: self.x, y, z: self.z }
+    }
+
+    /// Returns a copy of the vector with `z` replaced.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(1.0, 1.0, 1.0);
+    /// assert_eq!(v.with_z(5.0), Vec3::new(1.0, 1.0, 5.0));
+    /// ```
+    pub fn with_z(&self, z: f64) -> Vec3 {
+        Vec3 { x: self.x, y: self.y, z }
+    }
+
+    /// **Reflects** the vector off a surface with the given `normal`.
+    ///
+    /// Computed as `v - 2(v · n)n`. `normal` should be unit length.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(1.0, -1.0, 0.0);
+    /// assert_eq!(v.reflect(&Vec3::UNIT_Y), Vec3::new(1.0, 1.0, 0.0));
+    /// ```
+    pub fn reflect(&self, normal: &Vec3) -> Vec3 {
+        *self - 2.0 * self.dot(normal) * *normal
+    }
+
+    /// **Reflects** the vector off a surface with the given `normal`, which
+    /// doesn't need to be unit length.
+    ///
+    /// Computed as `v - 2((v · n) / (n · n))n`. Unlike [`reflect`](Vec3::reflect),
+    /// which gives a wrong answer for a non-unit normal, this divides by the
+    /// squared length of `normal`, at the cost of one extra dot product and
+    /// division. The result is `NaN` if `normal` is zero.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(1.0, -1.0, 0.0);
+    /// let n = Vec3::new(0.0, 5.0, 0.0);
+    /// assert_eq!(v.reflect_unnormalized(&n), Vec3::new(1.0, 1.0, 0.0));
+    /// ```
+    pub fn reflect_unnormalized(&self, normal: &Vec3) -> Vec3 {
+        *self - 2.0 * (self.dot(normal) / normal.mag_sq()) * *normal
+    }
+
+    /// **Bounces** the vector off a surface with the given `normal`, scaling the
+    /// part of the vector along the normal by `restitution`.
+    ///
+    /// A `restitution` of `1.0` is a perfect bounce (the same as
+    /// [`reflect`](Vec3::reflect)), and `0.0` removes the normal part entirely so
+    /// the vector slides along the surface. `normal` should be unit length.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(1.0, -2.0, 0.0);
+    /// assert_eq!(v.bounce(&Vec3::UNIT_Y, 0.5), Vec3::new(1.0, 1.0, 0.0));
+    /// ```
+    pub fn bounce(&self, normal: &Vec3, restitution: f64) -> Vec3 {
+        let normal_part = self.dot(normal) * *normal;
+        *self - (1.0 + restitution) * normal_part
+    }
+
+    /// Snaps each component to the nearest multiple of `cell`, e.g. to line a
+    /// position up with a cubic voxel grid.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(1.3, 2.7, -0.4);
+    /// assert_eq!(v.snap_to_grid(1.0), Vec3::new(1.0, 3.0, 0.0));
+    /// ```
+    pub fn snap_to_grid(&self, cell: f64) -> Vec3 {
+        self.map(|c| (c / cell).round() * cell)
+    }
+
+    /// Snaps each component to the nearest multiple of the matching component of
+    /// `cell`, for grids whose cells aren't cubes.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(1.3, 2.7, 5.0);
+    /// assert_eq!(v.snap_to_grid_per_axis(Vec3::new(1.0, 2.0, 4.0)), Vec3::new(1.0, 2.0, 4.0));
+    /// ```
+    pub fn snap_to_grid_per_axis(&self, cell: Vec3) -> Vec3 {
+        Vec3 {
+            x: (self.x / cell.x).round() * cell.x,
+            y: (self.y / cell.y).round() * cell.y,
+            z: (self.z / cell.z).round() * cell.z,
+        }
+    }
+
+    /// Rounds each component **down** and converts it to an integer, e.g. to
+    /// find the voxel containing a point.
+    ///
+    /// The conversion uses `as i64`, so components outside the `i64` range
+    /// saturate to `i64::MIN`/`i64::MAX` and `NaN` becomes `0`.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// assert_eq!(Vec3::new(1.7, -0.2, 3.0).floor_to_i64(), [1, -1, 3]);
+    /// ```
+    pub fn floor_to_i64(&self) -> [i64; 3] {
+        [self.x.floor() as i64, self.y.floor() as i64, self.z.floor() as i64]
+    }
+
+    /// Rounds each component to the **nearest** integer (halfway cases away from
+    /// zero) and converts it with `as i64`.
+    ///
+    /// Out-of-range components saturate and `NaN` becomes `0`, as with
+    /// [`floor_to_i64`](Vec3::floor_to_i64).
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// assert_eq!(Vec3::new(1.7, -0.2, -2.5).round_to_i64(), [2, 0, -3]);
+    /// ```
+    pub fn round_to_i64(&self) -> [i64; 3] {
+        [self.x.round() as i64, self.y.round() as i64, self.z.round() as i64]
+    }
+
+    /// Returns `true` if every component of `self` is **exactly** equal to the
+    /// matching component of `other`, with no tolerance.
+    ///
+    /// This uses float `==` per component, so `0.0` equals `-0.0` and `NaN`
+    /// never equals anything. Unlike `==` on [`Vec3`], vectors one rounding
+    /// step apart are not equal.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let a = Vec3::new(0.1 + 0.2, 0.0, 0.0);
+    /// let b = Vec3::new(0.3, 0.0, 0.0);
+    /// assert_eq!(a, b);
+    /// assert!(!a.exact_eq(&b));
+    /// ```
+    pub fn exact_eq(&self, other: &Vec3) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z
+    }
+
+    /// Returns `true` if every component of `self` is within `eps` of the
+    /// matching component of `other`.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let a = Vec3::new(1.0, 2.0, 3.0);
+    /// assert!(a.abs_diff_eq(&Vec3::new(1.05, 2.0, 3.0), 0.1));
+    /// assert!(!a.abs_diff_eq(&Vec3::new(1.5, 2.0, 3.0), 0.1));
+    /// ```
+    pub fn abs_diff_eq(&self, other: &Vec3, eps: f64) -> bool {
+        approx_eq(self.x, other.x, eps)
+            && approx_eq(self.y, other.y, eps)
+            && approx_eq(self.z, other.z, eps)
+    }
+
+    // All functions below this point are variations of the above functions
+
+    /// Returns the **magnitude**  of the vector, squared.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// let v = Vec3::new(3.0, 4.0, 0.0);
+    /// assert_eq!(v.mag_sq(), 25.0);
+    /// ```
+    pub fn mag_sq(&self) -> f64 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    /// Returns the **infinity norm** (L∞) of the vector, its largest absolute
+    /// component.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// assert_eq!(Vec3::new(1.0, -4.0, 2.0).norm_inf(), 4.0);
+    /// ```
+    pub fn norm_inf(&self) -> f64 {
+        self.x.abs().max(self.y.abs()).max(self.z.abs())
+    }
+
+    /// Returns the **L1 norm** of the vector, the sum of its absolute
+    /// components.
+    ///
+    /// # Examples
+    /// ```
+    ///  use lars::Vec3;
+    /// assert_eq!(Vec3::new(1.0, -4.0, 2.0).norm_l1(), 7.0);
+    /// ```
+    pub fn norm_l1(&self) -> f64 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+}
+
+/// Implements scalar multiplication of a vector by a float (`f64`).
+///
+/// This enables `f64 * Vec3` syntax.
+///
+/// # Examples
+/// ```
+///  use lars::Vec3;
+/// let v = Vec3::new(1.0, 2.0, 3.0);
+/// let scaled = 2.0 * v;
+/// assert_eq!(scaled, Vec3::new(2.0, 4.0, 6.0));
+/// ```
+impl Mul<Vec3> for f64 {
+    type Output = Vec3;
+    fn mul(self, vector: Vec3) -> Vec3 {
+        Vec3 {
+            x: self * vector.x,
+            y: self * vector.y,
+            z: self * vector.z,
+        }
+    }
+}
+
+/// displays the vector in the form (X, Y, Z)
+impl fmt::Display for Vec3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// Parses a vector from a string such as `"1, 2, 3"`, `"(1, 2, 3)"` or `"1 2 3"`.
+///
+/// This accepts the format written by the [`Display`](fmt::Display) impl.
+///
+/// # Examples
+/// ```
+/// use lars::Vec3;
+/// let v: Vec3 = "(1, 2.5, -3)".parse().unwrap();
+/// assert_eq!(v, Vec3::new(1.0, 2.5, -3.0));
+/// ```
+impl FromStr for Vec3 {
+    type Err = ParseVecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_components(s)?;
+        Ok(Vec3 { x, y, z })
+    }
+}
+
+/// Returns (0.0, 0.0, 0.0)
+impl Default for Vec3 {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+/// Implements **component-wise multiplication** between two [`Vec3`]s.
+///
+/// This is useful for operations such as color blending or per-component scaling.
+///
+/// # Examples
+/// ```
+///  use lars::Vec3;
+/// let a = Vec3::new(1.0, 2.0, 3.0);
+/// let b = Vec3::new(2.0, 0.5, 4.0);
+/// assert_eq!(a * b, Vec3::new(2.0, 1.0, 12.0));
+/// ```
+impl Mul<Vec3> for Vec3 {
+    type Output = Vec3;
+    fn mul(self, vector: Vec3) -> Vec3 {
+        Vec3 {
+            x: self.x * vector.x,
+            y: self.y * vector.y,
+            z: self.z * vector.z,
+        }
+    }
+}
+
+/// Implements **component-wise division** between two [`Vec3`]s.
+///
+/// Dividing by a zero component gives an infinite or `NaN` component; use
+/// [`checked_div`](Vec3::checked_div) to catch that case.
+///
+/// # Examples
+/// ```
+///  use lars::Vec3;
+/// let a = Vec3::new(6.0, 8.0, 10.0);
+/// let b = Vec3::new(2.0, 4.0, 5.0);
+/// assert_eq!(a / b, Vec3::new(3.0, 2.0, 2.0));
+/// ```
+impl Div<Vec3> for Vec3 {
+    type Output = Vec3;
+    fn div(self, vector: Vec3) -> Vec3 {
+        Vec3 {
+            x: self.x / vector.x,
+            y: self.y / vector.y,
+            z: self.z / vector.z,
+        }
+    }
+}
+
+impl Mul<Mat3> for Vec3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Mat3) -> Vec3 {
+        Vec3 {
+            x: self.x * rhs.a + self.y * rhs.d + self.z * rhs.g,
+            y: self.x * rhs.b + self.y * rhs.e + self.z * rhs.h,
+            z: self.x * rhs.c + self.y * rhs.f + self.z * rhs.i,
+        }
+    }
+}
+
+/// Represents an RGB color with values between `0.0` and `1.0`.
+/// Will eventually contain support for conversions with the image crate
+///
+/// Alias for [`Vec3`].
+pub type Colour = Vec3;
+impl Colour {
+    /// Returns the perceptual **luminance** of the colour, using the Rec. 709 weights.
+    ///
+    /// \[
+    /// Y = 0.2126R + 0.7152G + 0.0722B
+    /// \]
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Colour;
+    /// let white = Colour::ONE;
+    /// assert_eq!(white.luminance(), 1.0);
+    /// ```
+    pub fn luminance(&self) -> f64 {
+        0.2126 * self.x + 0.7152 * self.y + 0.0722 * self.z
+    }
+
+    /// Converts the colour to **grayscale**, with its luminance in every channel.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Colour;
+    /// let red = Colour::new(1.0, 0.0, 0.0);
+    /// assert_eq!(red.grayscale(), Colour::new(0.2126, 0.2126, 0.2126));
+    /// ```
+    pub fn grayscale(&self) -> Colour {
+        let y = self.luminance();
+        Colour::new(y, y, y)
+    }
+
+    /// Builds a colour from **HSV** (hue, saturation, value).
+    ///
+    /// `h` is the hue in degrees (`0.0`–`360.0`, wrapping outside that range),
+    /// `s` and `v` are in `0.0`–`1.0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Colour;
+    /// assert_eq!(Colour::from_hsv(120.0, 1.0, 1.0), Colour::new(0.0, 1.0, 0.0));
+    /// ```
+    pub fn from_hsv(h: f64, s: f64, v: f64) -> Colour {
+        let chroma = v * s;
+        let sector = h.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        let m = v - chroma;
+        Colour::new(r + m, g + m, b + m)
+    }
+
+    /// Converts the colour to **HSV**, returning `(h, s, v)`.
+    ///
+    /// The hue is in degrees (`0.0`–`360.0`). For achromatic colours (greys, where
+    /// `s == 0`) the hue is undefined and `0.0` is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Colour;
+    /// let blue = Colour::new(0.0, 0.0, 1.0);
+    /// assert_eq!(blue.to_hsv(), (240.0, 1.0, 1.0));
+    /// ```
+    pub fn to_hsv(&self) -> (f64, f64, f64) {
+        let max = self.x.max(self.y).max(self.z);
+        let min = self.x.min(self.y).min(self.z);
+        let chroma = max - min;
+
+        let h = if chroma == 0.0 {
+            0.0
+        } else if max == self.x {
+            60.0 * ((self.y - self.z) / chroma).rem_euclid(6.0)
+        } else if max == self.y {
+            60.0 * ((self.z - self.x) / chroma + 2.0)
+        } else {
+            60.0 * ((self.x - self.y) / chroma + 4.0)
+        };
+        let s = if max == 0.0 { 0.0 } else { chroma / max };
+
+        (h, s, max)
+    }
+
+    /// Adds `other` to the colour, clamping each channel of the sum to `[0, 1]`.
+    ///
+    /// Unlike plain `+`, repeated additive blending can't push a channel past
+    /// full intensity.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Colour;
+    /// let c = Colour::new(0.8, 0.0, 0.0).add_saturating(&Colour::new(0.5, 0.0, 0.0));
+    /// assert_eq!(c, Colour::new(1.0, 0.0, 0.0));
+    /// ```
+    pub fn add_saturating(&self, other: &Colour) -> Colour {
+        (*self + *other).saturate()
+    }
+
+    /// Blends `other` onto the colour with the **multiply** mode, `a · b`,
+    /// which can only darken.
+    ///
+    /// Both colours are clamped to `[0, 1]` first. For in-range colours this is
+    /// the same as the component-wise `*`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Colour;
+    /// let grey = Colour::new(0.5, 0.5, 0.5);
+    /// assert_eq!(grey.blend_multiply(&grey), Colour::new(0.25, 0.25, 0.25));
+    /// ```
+    pub fn blend_multiply(&self, other: &Colour) -> Colour {
+        self.saturate() * other.saturate()
+    }
+
+    /// Blends `other` onto the colour with the **screen** mode,
+    /// `1 - (1 - a)(1 - b)`, which can only lighten.
+    ///
+    /// Both colours are clamped to `[0, 1]` first.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Colour;
+    /// let grey = Colour::new(0.5, 0.5, 0.5);
+    /// assert_eq!(grey.blend_screen(&grey), Colour::new(0.75, 0.75, 0.75));
+    /// ```
+    pub fn blend_screen(&self, other: &Colour) -> Colour {
+        Colour::ONE - (Colour::ONE - self.saturate()) * (Colour::ONE - other.saturate())
+    }
+
+    /// Blends `other` onto the colour with the **overlay** mode, treating
+    /// `self` as the base layer.
+    ///
+    /// Each channel multiplies where the base is dark and screens where it is
+    /// light, which boosts contrast:
+    /// \[
+    /// \text{overlay}(a, b) =
+    /// \begin{cases}
+    /// 2ab & a < 0.5 \\
+    /// 1 - 2(1 - a)(1 - b) & \text{otherwise}
+    /// \end{cases}
+    /// \]
+    /// Both colours are clamped to `[0, 1]` first.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Colour;
+    /// let base = Colour::new(0.25, 0.5, 0.75);
+    /// let grey = Colour::new(0.5, 0.5, 0.5);
+    /// assert_eq!(base.blend_overlay(&grey), base);
+    /// ```
+    pub fn blend_overlay(&self, other: &Colour) -> Colour {
+        let overlay = |a: f64, b: f64| {
+            if a < 0.5 {
+                2.0 * a * b
+            } else {
+                1.0 - 2.0 * (1.0 - a) * (1.0 - b)
+            }
+        };
+        let (a, b) = (self.saturate(), other.saturate());
+        Colour::new(overlay(a.x, b.x), overlay(a.y, b.y), overlay(a.z, b.z))
+    }
+}
+
+/// Represents a 3D point in space.
+///
+/// Alias for [`Vec3`].
+pub type Point3D = Vec3;
+impl Point3D {
+    /// Finds the unsigned distance between `self` and another 3D point `Other`.
+    ///
+    /// #examples
+    ///
+    /// ```
+    /// use lars::{Point3D};
+    /// let a = Point3D::new(1.0, 0.0, 0.0);
+    /// let b = Point3D::new(0.0, 0.0, 0.0);
+    /// assert_eq!(a.dist(&b), 1.0)
+    /// ```
+    pub fn dist(&self, other: &Point3D) -> f64 {
+        (*self - *other).mag().abs()
+    }
+    /// Finds the unsigned distance between `self` and another 3D point `Other`, squared
+    ///
+    /// #examples
+    ///
+    /// ```
+    ///
+    /// use lars::Point3D;
+    /// let a = Point3D::new(2.0, 0.0, 0.0);
+    /// let b = Point3D::new(0.0, 0.0, 0.0);
+    /// assert_eq!(a.dist_sq(&b), 4.0)
+    ///
+    /// ```
+    pub fn dist_sq(&self, other: &Point3D) -> f64 {
+        (*self - *other).mag_sq().abs()
+    }
+
+    /// Alias for [`dist`](Point3D::dist), matching the naming used by other math crates.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Point3D;
+    /// let a = Point3D::new(1.0, 0.0, 0.0);
+    /// let b = Point3D::new(4.0, 4.0, 0.0);
+    /// assert_eq!(a.distance(&b), 5.0);
+    /// ```
+    pub fn distance(&self, other: &Point3D) -> f64 {
+        self.dist(other)
+    }
+
+    /// Alias for [`dist_sq`](Point3D::dist_sq), matching the naming used by other math crates.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Point3D;
+    /// let a = Point3D::new(1.0, 0.0, 0.0);
+    /// let b = Point3D::new(4.0, 4.0, 0.0);
+    /// assert_eq!(a.distance_squared(&b), 25.0);
+    /// ```
+    pub fn distance_squared(&self, other: &Point3D) -> f64 {
+        self.dist_sq(other)
+    }
+
+    /// Returns the **Manhattan** (L1) distance between `self` and `other`, the sum
+    /// of the absolute differences of their components.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Point3D;
+    /// let a = Point3D::new(1.0, 2.0, 3.0);
+    /// let b = Point3D::new(4.0, 0.0, 3.0);
+    /// assert_eq!(a.distance_manhattan(&b), 5.0);
+    /// ```
+    pub fn distance_manhattan(&self, other: &Point3D) -> f64 {
+        (*self - *other).map(f64::abs).component_sum()
+    }
+
+    /// Returns the **Chebyshev** (L∞) distance between `self` and `other`, the
+    /// largest absolute difference of their components.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Point3D;
+    /// let a = Point3D::new(1.0, 2.0, 3.0);
+    /// let b = Point3D::new(4.0, 0.0, 3.0);
+    /// assert_eq!(a.distance_chebyshev(&b), 3.0);
+    /// ```
+    pub fn distance_chebyshev(&self, other: &Point3D) -> f64 {
+        (*self - *other).map(f64::abs).component_max()
+    }
+
+    /// Moves `self` towards `target` by at most `max_delta`.
+    ///
+    /// Returns `target` if it is within `max_delta`, otherwise takes a step of
+    /// exactly `max_delta` along the line towards it.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Point3D;
+    /// let a = Point3D::ZERO;
+    /// let b = Point3D::new(10.0, 0.0, 0.0);
+    /// assert_eq!(a.move_towards(&b, 1.0), Point3D::new(1.0, 0.0, 0.0));
+    /// assert_eq!(a.move_towards(&b, 20.0), b);
+    /// ```
+    pub fn move_towards(&self, target: &Point3D, max_delta: f64) -> Point3D {
+        let delta = *target - *self;
+        let distance = delta.mag();
+        if distance <= max_delta || distance == 0.0 {
+            return *target;
+        }
+        *self + delta / distance * max_delta
+    }
+
+    /// Returns the point inside the axis-aligned box `[min, max]` closest to
+    /// `self`, by clamping each component to the box's range on that axis.
+    ///
+    /// Points already inside the box are returned unchanged.
+    ///
+    /// # Panics
+    /// Panics if any component of `min` is greater than the matching component
+    /// of `max`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::Point3D;
+    /// let p = Point3D::new(-1.0, 0.5, 3.0);
+    /// assert_eq!(p.clamp_to_aabb(&Point3D::ZERO, &Point3D::ONE), Point3D::new(0.0, 0.5, 1.0));
+    /// ```
+    pub fn clamp_to_aabb(&self, min: &Point3D, max: &Point3D) -> Point3D {
+        Point3D::new(
+            self.x.clamp(min.x, max.x),
+            self.y.clamp(min.y, max.y),
+            self.z.clamp(min.z, max.z),
+        )
+    }
+
+    /// Returns the **signed distance** from `self` to the plane through
+    /// `plane_point` with unit normal `plane_normal`.
+    ///
+    /// The distance is positive on the side the normal points to and negative
+    /// on the other side. There is no plane type; the plane is passed as a point
+    /// on it and its normal.
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Point3D, Vec3};
+    /// let p = Point3D::new(1.0, -2.0, 5.0);
+    /// assert_eq!(p.distance_to_plane(&Point3D::ZERO, &Vec3::UNIT_Y), -2.0);
+    /// ```
+    pub fn distance_to_plane(&self, plane_point: &Point3D, plane_normal: &Vec3) -> f64 {
+        (*self - *plane_point).dot(plane_normal)
+    }
+
+    /// Returns the point on the plane through `plane_point` with unit normal
+    /// `plane_normal` closest to `self`, found by moving `self` along the
+    /// normal by its [signed distance](Point3D::distance_to_plane).
+    ///
+    /// # Examples
+    /// ```
+    /// use lars::{Point3D, Vec3};
+    /// let p = Point3D::new(1.0, 4.0, 5.0);
+    /// let q = p.closest_point_on_plane(&Point3D::new(0.0, 1.0, 0.0), &Vec3::UNIT_Y);
+    /// assert_eq!(q, Point3D::new(1.0, 1.0, 5.0));
+    /// ```
+    pub fn closest_point_on_plane(&self, plane_point: &Point3D, plane_normal: &Vec3) -> Point3D {
+        *self - self.distance_to_plane(plane_point, plane_normal) * *plane_normal
+    }
+}
+
+/// A [`Vec3`] wrapper that can be used as a key in a `HashMap` or `HashSet`.
+///
+/// `f64` is neither `Hash` nor `Eq`, so this compares and hashes the **raw bits**
+/// of each component via [`f64::to_bits`]. Unlike the tolerant `==` on [`Vec3`],
+/// two vectors are only equal here if they are bit-for-bit identical, which means:
+/// - `NaN` is equal to itself if it has the same bit pattern.
+/// - `0.0` and `-0.0` are **not** equal.
+///
+/// # Examples
+/// ```
+/// use lars::{HashableVec3, Vec3};
+/// use std::collections::HashSet;
+///
+/// let mut vertices = HashSet::new();
+/// vertices.insert(HashableVec3(Vec3::ONE));
+/// vertices.insert(HashableVec3(Vec3::ONE));
+/// assert_eq!(vertices.len(), 1);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct HashableVec3(pub Vec3);
+
+impl HashableVec3 {
+    fn bits(&self) -> [u64; 3] {
+        [self.0.x.to_bits(), self.0.y.to_bits(), self.0.z.to_bits()]
+    }
+}
+
+impl PartialEq for HashableVec3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+impl Eq for HashableVec3 {}
+
+impl Hash for HashableVec3 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
+}
+
+impl From<Vec3> for HashableVec3 {
+    fn from(v: Vec3) -> Self {
+        HashableVec3(v)
+    }
+}
+
+impl From<HashableVec3> for Vec3 {
+    fn from(h: HashableVec3) -> Self {
+        h.0
+    }
+}
+
+/// Compares two [`Vec3`]s element by element, within an absolute tolerance.
+///
+/// Available with the `approx` feature.
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Vec3 {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        EPSILON
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon)
+            && self.y.abs_diff_eq(&other.y, epsilon)
+            && self.z.abs_diff_eq(&other.z, epsilon)
+    }
+}
+
+/// Compares two [`Vec3`]s element by element, within a relative tolerance.
+///
+/// Available with the `approx` feature.
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Vec3 {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+            && self.z.relative_eq(&other.z, epsilon, max_relative)
+    }
+}
+
+/// Converts from a `mint` vector. Available with the `mint` feature.
+#[cfg(feature = "mint")]
+impl From<mint::Vector3<f64>> for Vec3 {
+    fn from(v: mint::Vector3<f64>) -> Self {
+        Vec3::new(v.x, v.y, v.z)
+    }
+}
+
+/// Converts to a `mint` vector. Available with the `mint` feature.
+#[cfg(feature = "mint")]
+impl From<Vec3> for mint::Vector3<f64> {
+    fn from(v: Vec3) -> Self {
+        mint::Vector3 { x: v.x, y: v.y, z: v.z }
+    }
+}
+
+/// Returns [`Vec3::ZERO`]. Available with the `num-traits` feature.
+#[cfg(feature = "num-traits")]
+impl num_traits::Zero for Vec3 {
+    fn zero() -> Self {
+        Vec3::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Vec3::ZERO
+    }
+}
+
+// TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_add() {
+        let v1 = Vec3::ZERO;
+        let v2 = Vec3::ONE;
+        assert_eq!(v1 + v2, Vec3::ONE);
+    }
+    #[test]
+    fn test_mag() {
+        let v = Vec3::new(1.0, 2.0, 2.0);
+        assert_eq!(v.mag(), 3.0);
+    }
+    #[test]
+    fn test_mag_sq() {
+        let v = Vec3::new(1.0, 2.0, 2.0);
+        assert_eq!(v.mag_sq(), 9.0);
+    }
+    #[test]
+    fn test_dist() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(1.0, 0.0, 3.0);
+        assert_eq!(a.dist(&b), 2.0);
+    }
+    #[test]
+    fn test_dist_sq() {
+        let a = Point3D::new(1.0, 2.0, 3.0);
+        let b = Point3D::new(1.0, 0.0, 3.0);
+        assert_eq!(a.dist_sq(&b), 4.0);
+    }
+    #[test]
+    fn test_dot_product() {
+        let a = Point3D::new(1.0, 2.0, 3.0);
+        let b = Point3D::new(4.0, -5.0, 6.0);
+        assert_eq!(a.dot(&b), 12.0);
+    }
+
+    #[test]
+    fn test_cross_product() {
+        let a = Vec3::new(1.0, 0.0, 0.0);
+        let b = Vec3::new(0.0, 1.0, 0.0);
+        assert_eq!(a.cross(&b), Vec3::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let v = Vec3::new(3.0, 4.0, 0.0);
+        let n = v.normalize();
+        assert_abs_diff_eq!(n.mag(), 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(2.0 * v, Vec3::new(2.0, 4.0, 6.0));
+    }
+
+    #[test]
+    fn test_component_mul() {
+        let a = Vec3::new(2.0, 3.0, 4.0);
+        let b = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(a * b, Vec3::new(2.0, 6.0, 12.0));
+    }
+
+    #[test]
+    fn test_default() {
+        let v = Vec3::default();
         assert_eq!(v, Vec3::ZERO);
     }
 
@@ -385,4 +1738,590 @@ mod tests {
         );
         assert_eq!(v * m, v);
     }
+
+    #[test]
+    fn test_to_array() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.to_array(), [v.x, v.y, v.z]);
+    }
+
+    #[test]
+    fn test_as_slice() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.as_slice().len(), 3);
+        assert_eq!(v.as_slice()[1], v.y);
+        assert_eq!(v.as_slice()[2], v.z);
+    }
+
+    #[test]
+    fn test_hashable_dedup() {
+        use std::collections::HashSet;
+        let vertices = [
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(4.0, 5.0, 6.0),
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(4.0, 5.0, 6.0),
+        ];
+        let unique: HashSet<HashableVec3> = vertices.iter().map(|&v| HashableVec3(v)).collect();
+        assert_eq!(unique.len(), 2);
+        assert!(unique.contains(&HashableVec3(Vec3::new(1.0, 2.0, 3.0))));
+    }
+
+    #[test]
+    fn test_hashable_signed_zero() {
+        assert_ne!(HashableVec3(Vec3::ZERO), HashableVec3(-Vec3::ZERO));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(3.0, 6.0, 9.0);
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+        assert_eq!(a.lerp(&b, 0.5), Vec3::new(2.0, 4.0, 6.0));
+    }
+
+    #[test]
+    fn test_lerp_clamped() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(3.0, 6.0, 9.0);
+        assert_eq!(a.lerp_clamped(&b, -1.0), a);
+        assert_eq!(a.lerp_clamped(&b, 2.0), b);
+        assert_eq!(a.lerp_clamped(&b, 0.5), a.lerp(&b, 0.5));
+    }
+
+    #[test]
+    fn test_smoothstep() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(3.0, 6.0, 9.0);
+        assert_eq!(a.smoothstep(&b, 0.0), a);
+        assert_eq!(a.smoothstep(&b, 1.0), b);
+        assert_eq!(a.smoothstep(&b, 0.5), a.lerp(&b, 0.5));
+        assert_eq!(a.smoothstep(&b, 5.0), b);
+    }
+
+    #[test]
+    fn test_orthonormal_basis() {
+        let normals = [
+            Vec3::UNIT_X,
+            Vec3::UNIT_Y,
+            -Vec3::UNIT_Z,
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-0.3, 0.1, -5.0),
+        ];
+        for n in normals {
+            let (a, b) = n.orthonormal_basis();
+            assert_abs_diff_eq!(a.mag(), 1.0, epsilon = 1e-10);
+            assert_abs_diff_eq!(b.mag(), 1.0, epsilon = 1e-10);
+            assert_abs_diff_eq!(a.dot(&b), 0.0, epsilon = 1e-10);
+            assert_abs_diff_eq!(a.dot(&n), 0.0, epsilon = 1e-10);
+            assert_abs_diff_eq!(b.dot(&n), 0.0, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_luminance() {
+        assert_eq!(Colour::ONE.luminance(), 1.0);
+        assert_eq!(Colour::ZERO.luminance(), 0.0);
+    }
+
+    #[test]
+    fn test_grayscale_unchanged() {
+        let grey = Colour::new(0.4, 0.4, 0.4);
+        assert_eq!(grey.grayscale(), grey);
+    }
+
+    #[test]
+    fn test_hsv_round_trip() {
+        let cases = [
+            (0.0, Colour::new(1.0, 0.0, 0.0)),
+            (120.0, Colour::new(0.0, 1.0, 0.0)),
+            (240.0, Colour::new(0.0, 0.0, 1.0)),
+        ];
+        for (h, rgb) in cases {
+            assert_eq!(Colour::from_hsv(h, 1.0, 1.0), rgb);
+            let (h2, s2, v2) = rgb.to_hsv();
+            assert_abs_diff_eq!(h2, h, epsilon = 1e-10);
+            assert_eq!((s2, v2), (1.0, 1.0));
+            assert_eq!(Colour::from_hsv(h2, s2, v2), rgb);
+        }
+    }
+
+    #[test]
+    fn test_hsv_grey() {
+        let grey = Colour::new(0.5, 0.5, 0.5);
+        assert_eq!(grey.to_hsv(), (0.0, 0.0, 0.5));
+        assert_eq!(Colour::from_hsv(200.0, 0.0, 0.5), grey);
+    }
+
+    #[test]
+    fn test_hsv_arbitrary() {
+        let c = Colour::new(0.2, 0.6, 0.4);
+        let (h, s, v) = c.to_hsv();
+        assert_eq!(Colour::from_hsv(h, s, v), c);
+    }
+
+    #[test]
+    fn test_component_reductions() {
+        let v = Vec3::new(-3.0, 5.0, -0.5);
+        assert_eq!(v.component_sum(), 1.5);
+        assert_eq!(v.component_min(), -3.0);
+        assert_eq!(v.component_max(), 5.0);
+    }
+
+    #[test]
+    fn test_recip() {
+        let v = Vec3::new(2.0, 4.0, 0.5);
+        assert_eq!(v.recip(), Vec3::new(0.5, 0.25, 2.0));
+        assert_eq!(v.try_recip(), Some(Vec3::new(0.5, 0.25, 2.0)));
+    }
+
+    #[test]
+    fn test_try_recip_zero() {
+        let v = Vec3::new(1.0, 2.0, 0.0);
+        assert_eq!(v.try_recip(), None);
+    }
+
+    #[test]
+    fn test_outer() {
+        let m = Vec3::UNIT_X.outer(&Vec3::UNIT_Y);
+        let mut expected = Mat3::ZERO;
+        expected.b = 1.0;
+        assert_eq!(m, expected);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let v = Vec3::new(4.0, 9.0, 16.0);
+        assert_eq!(v.sqrt(), Vec3::new(2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn test_powf() {
+        let v = Vec3::new(2.0, -3.0, 0.5);
+        assert_eq!(v.powf(2.0), Vec3::new(4.0, 9.0, 0.25));
+    }
+
+    #[test]
+    fn test_distance_aliases() {
+        let a = Point3D::new(1.0, 0.0, 0.0);
+        let b = Point3D::new(4.0, 4.0, 0.0);
+        assert_eq!(a.distance(&b), a.dist(&b));
+        assert_eq!(a.distance_squared(&b), a.dist_sq(&b));
+    }
+
+    #[test]
+    fn test_normalize_or() {
+        let v = Vec3::new(0.0, 3.0, 4.0);
+        assert_eq!(v.normalize_or(Vec3::UNIT_X), v.normalize());
+        assert_eq!(v.normalize_or_zero(), v.normalize());
+        assert_eq!(Vec3::ZERO.normalize_or(Vec3::UNIT_X), Vec3::UNIT_X);
+        assert_eq!(Vec3::ZERO.normalize_or_zero(), Vec3::ZERO);
+        assert_eq!(Vec3::ZERO.try_normalize(), None);
+    }
+
+    #[test]
+    fn test_clamp_scalar() {
+        let v = Vec3::new(-5.0, 2.0, 10.0);
+        assert_eq!(v.clamp_scalar(0.0, 5.0), Vec3::new(0.0, 2.0, 5.0));
+    }
+
+    #[test]
+    fn test_saturate() {
+        let c = Colour::new(1.5, 0.25, 1.0);
+        assert_eq!(c.saturate(), Colour::new(1.0, 0.25, 1.0));
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn test_approx() {
+        use approx::{assert_abs_diff_ne, assert_relative_eq};
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(1.0, 2.0, 3.0 + 1e-12);
+        assert_abs_diff_eq!(a, b);
+        assert_relative_eq!(a, b);
+        assert_abs_diff_ne!(a, a * 2.0);
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn test_mint_round_trip() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        let m: mint::Vector3<f64> = v.into();
+        assert_eq!((m.x, m.y, m.z), (1.0, 2.0, 3.0));
+        assert_eq!(Vec3::from(m), v);
+    }
+
+    #[test]
+    fn test_move_towards_step() {
+        let a = Point3D::new(1.0, 1.0, 1.0);
+        let b = Point3D::new(1.0, 5.0, 4.0);
+        let moved = a.move_towards(&b, 2.5);
+        assert_eq!(moved, Point3D::new(1.0, 3.0, 2.5));
+        assert_abs_diff_eq!(moved.dist(&a), 2.5, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_move_towards_arrive() {
+        let a = Point3D::new(1.0, 1.0, 1.0);
+        let b = Point3D::new(1.0, 5.0, 4.0);
+        assert_eq!(a.move_towards(&b, 5.0), b);
+        assert_eq!(a.move_towards(&b, 100.0), b);
+        assert_eq!(b.move_towards(&b, 0.0), b);
+    }
+
+    #[test]
+    fn test_max_axis() {
+        assert_eq!(Vec3::new(3.0, 1.0, 2.0).max_axis(), 0);
+        assert_eq!(Vec3::new(1.0, 3.0, 2.0).max_axis(), 1);
+        assert_eq!(Vec3::new(1.0, 2.0, 3.0).max_axis(), 2);
+        assert_eq!(Vec3::new(1.0, 3.0, 3.0).max_axis(), 1);
+    }
+
+    #[test]
+    fn test_min_axis() {
+        assert_eq!(Vec3::new(-3.0, 1.0, 2.0).min_axis(), 0);
+        assert_eq!(Vec3::new(1.0, -3.0, 2.0).min_axis(), 1);
+        assert_eq!(Vec3::new(1.0, 2.0, -3.0).min_axis(), 2);
+        assert_eq!(Vec3::ONE.min_axis(), 0);
+    }
+
+    #[test]
+    fn test_spherical_round_trip() {
+        let vectors = [
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-4.0, 0.5, -1.0),
+            Vec3::new(0.0, -2.0, 0.0),
+            Vec3::UNIT_X,
+        ];
+        for v in vectors {
+            let (r, theta, phi) = v.to_spherical();
+            assert_eq!(Vec3::from_spherical(r, theta, phi), v);
+        }
+    }
+
+    #[test]
+    fn test_spherical_poles() {
+        let up = Vec3::new(0.0, 0.0, 2.0);
+        assert_eq!(up.to_spherical(), (2.0, 0.0, 0.0));
+        assert_eq!(Vec3::from_spherical(2.0, 0.0, 0.0), up);
+
+        let (r, theta, _) = (-up).to_spherical();
+        assert_eq!((r, theta), (2.0, std::f64::consts::PI));
+        assert_eq!(Vec3::ZERO.to_spherical(), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_from_str() {
+        let expected = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!("1,2,3".parse::<Vec3>(), Ok(expected));
+        assert_eq!("(1.0, 2.0, 3.0)".parse::<Vec3>(), Ok(expected));
+        assert_eq!("1 2 3".parse::<Vec3>(), Ok(expected));
+        assert_eq!(expected.to_string().parse::<Vec3>(), Ok(expected));
+    }
+
+    #[test]
+    fn test_from_str_whitespace() {
+        let expected = Vec3::new(1.0, -2.0, 3.5);
+        assert_eq!("  ( 1 ,  -2 ,3.5 )  ".parse::<Vec3>(), Ok(expected));
+        assert_eq!("\t1   -2\n3.5".parse::<Vec3>(), Ok(expected));
+    }
+
+    #[test]
+    fn test_from_str_wrong_count() {
+        assert_eq!(
+            "1, 2".parse::<Vec3>(),
+            Err(ParseVecError::WrongComponentCount { expected: 3, found: 2 })
+        );
+        assert_eq!(
+            "(1, 2, 3, 4)".parse::<Vec3>(),
+            Err(ParseVecError::WrongComponentCount { expected: 3, found: 4 })
+        );
+    }
+
+    #[test]
+    fn test_from_str_garbage() {
+        assert!(matches!(
+            "1, two, 3".parse::<Vec3>(),
+            Err(ParseVecError::InvalidComponent(_))
+        ));
+        assert!(matches!(
+            "1,,3".parse::<Vec3>(),
+            Err(ParseVecError::InvalidComponent(_))
+        ));
+        assert!("hello".parse::<Vec3>().is_err());
+    }
+
+    #[test]
+    fn test_faceforward_no_flip() {
+        let n = Vec3::UNIT_Z;
+        let incident = Vec3::new(1.0, 0.0, -1.0);
+        assert_eq!(n.faceforward(&incident, &n), n);
+    }
+
+    #[test]
+    fn test_faceforward_flip() {
+        let n = Vec3::UNIT_Z;
+        let incident = Vec3::new(1.0, 0.0, 1.0);
+        assert_eq!(n.faceforward(&incident, &n), -n);
+    }
+
+    #[test]
+    fn test_with_components() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.with_x(5.0), Vec3::new(5.0, 2.0, 3.0));
+        assert_eq!(v.with_y(5.0).y, 5.0);
+        assert_eq!(v.with_y(5.0), Vec3::new(1.0, 5.0, 3.0));
+        assert_eq!(v.with_z(0.0), Vec3::new(1.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn test_reflect() {
+        let v = Vec3::new(1.0, -1.0, 2.0);
+        let n = Vec3::UNIT_Y;
+        assert_eq!(v.reflect(&n), Vec3::new(1.0, 1.0, 2.0));
+        assert_eq!(v.reflect(&n).mag(), v.mag());
+    }
+
+    #[test]
+    fn test_bounce() {
+        let v = Vec3::new(1.0, -3.0, 2.0);
+        let n = Vec3::new(0.0, 1.0, 1.0).normalize();
+        assert_eq!(v.bounce(&n, 1.0), v.reflect(&n));
+
+        let slide = v.bounce(&n, 0.0);
+        assert_abs_diff_eq!(slide.dot(&n), 0.0, epsilon = 1e-10);
+        assert_eq!(slide, v - v.dot(&n) * n);
+    }
+
+    #[test]
+    fn test_snap_to_grid() {
+        let v = Vec3::new(1.3, 2.7, -3.6);
+        assert_eq!(v.snap_to_grid(1.0), Vec3::new(1.0, 3.0, -4.0));
+        assert_eq!(v.snap_to_grid_per_axis(Vec3::new(1.0, 0.5, 3.0)), Vec3::new(1.0, 2.5, -3.0));
+    }
+
+    #[test]
+    fn test_distance_metrics() {
+        let a = Point3D::new(1.0, -2.0, 3.0);
+        let b = Point3D::new(3.0, 1.0, -3.0);
+        assert_eq!(a.dist(&b), 7.0);
+        assert_eq!(a.distance_manhattan(&b), 11.0);
+        assert_eq!(a.distance_chebyshev(&b), 6.0);
+    }
+
+    #[test]
+    fn test_cos_angle() {
+        let a = Vec3::new(2.0, 0.0, 0.0);
+        assert_eq!(a.cos_angle(&Vec3::new(0.0, 3.0, 0.0)), 0.0);
+        assert_eq!(a.cos_angle(&Vec3::new(5.0, 0.0, 0.0)), 1.0);
+        assert_eq!(a.cos_angle(&Vec3::new(-1.0, 0.0, 0.0)), -1.0);
+        let b = Vec3::new(0.1, 0.2, 0.3);
+        assert!(b.cos_angle(&(b * 3.0)) <= 1.0);
+    }
+
+    #[test]
+    fn test_floor_and_round_to_i64() {
+        let v = Vec3::new(2.4, -2.4, -0.6);
+        assert_eq!(v.floor_to_i64(), [2, -3, -1]);
+        assert_eq!(v.round_to_i64(), [2, -2, -1]);
+        assert_eq!(Vec3::new(f64::MAX, f64::NAN, 0.5).round_to_i64(), [i64::MAX, 0, 1]);
+    }
+
+    #[test]
+    fn test_approx_zero() {
+        assert!(Vec3::ZERO.approx_zero());
+        let tiny = Vec3::new(1e-7, -1e-7, 0.0);
+        assert!(!tiny.approx_zero());
+        assert!(tiny.approx_zero_eps(1e-6));
+        assert!(!tiny.approx_zero_eps(1e-8));
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits() {
+        use num_traits::Zero;
+        assert!(Vec3::zero().is_zero());
+        assert!(!Vec3::ONE.is_zero());
+    }
+
+    #[test]
+    fn test_component_div() {
+        let a = Vec3::new(6.0, 8.0, 10.0);
+        let b = Vec3::new(2.0, 4.0, 5.0);
+        assert_eq!(a / b, Vec3::new(3.0, 2.0, 2.0));
+        assert_eq!(a / 2.0, Vec3::new(3.0, 4.0, 5.0));
+        assert_eq!(a.checked_div(&b), Some(a / b));
+        assert_eq!(a.checked_div(&Vec3::new(1.0, 1.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_rotate_around_axis() {
+        use std::f64::consts::FRAC_PI_2;
+        assert_eq!(Vec3::UNIT_X.rotate_around_axis(Vec3::UNIT_Z, FRAC_PI_2), Vec3::UNIT_Y);
+
+        let v = Vec3::new(1.0, -2.0, 0.5);
+        let axis = Vec3::new(1.0, 1.0, 2.0);
+        assert_eq!(v.rotate_around_axis(axis, 0.8), Mat3::from_axis_angle(axis, 0.8) * v);
+        assert_eq!(axis.rotate_around_axis(axis, 1.3), axis);
+    }
+
+    #[test]
+    fn test_reflect_unnormalized() {
+        let v = Vec3::new(3.0, -1.0, 2.0);
+        let n = Vec3::new(0.0, 1.2, 1.6);
+        assert_eq!(n.mag(), 2.0);
+        assert_eq!(v.reflect_unnormalized(&n), v.reflect(&n.normalize()));
+        assert_ne!(v.reflect(&n), v.reflect(&n.normalize()));
+    }
+
+    #[test]
+    fn test_add_saturating() {
+        let a = Colour::new(0.8, 0.2, 0.0);
+        let b = Colour::new(0.5, 0.3, -0.4);
+        assert_eq!(a.add_saturating(&b), Colour::new(1.0, 0.5, 0.0));
+        assert_eq!(a + b, Colour::new(1.3, 0.5, -0.4));
+    }
+
+    #[test]
+    fn test_clamp_length() {
+        let short = Vec3::new(0.0, 0.3, 0.4);
+        let long = Vec3::new(6.0, 0.0, 8.0);
+        assert_eq!(short.clamp_length(1.0, 5.0), Vec3::new(0.0, 0.6, 0.8));
+        assert_eq!(long.clamp_length(1.0, 5.0), Vec3::new(3.0, 0.0, 4.0));
+        assert_eq!(Vec3::new(1.0, 2.0, 2.0).clamp_length(1.0, 5.0), Vec3::new(1.0, 2.0, 2.0));
+
+        assert_eq!(short.clamp_length_min(1.0), Vec3::new(0.0, 0.6, 0.8));
+        assert_eq!(long.clamp_length_min(1.0), long);
+        assert_eq!(long.clamp_length_max(5.0), Vec3::new(3.0, 0.0, 4.0));
+        assert_eq!(short.clamp_length_max(5.0), short);
+        assert_eq!(Vec3::ZERO.clamp_length(1.0, 5.0), Vec3::ZERO);
+    }
+
+    #[test]
+    fn test_blend_modes() {
+        let white = Colour::ONE;
+        let black = Colour::ZERO;
+        let grey = Colour::new(0.5, 0.5, 0.5);
+        let c = Colour::new(0.2, 0.6, 1.0);
+
+        assert_eq!(c.blend_multiply(&white), c);
+        assert_eq!(c.blend_multiply(&black), black);
+        assert_eq!(c.blend_multiply(&grey), Colour::new(0.1, 0.3, 0.5));
+        assert_eq!(c.blend_multiply(&Colour::new(2.0, 2.0, 2.0)), c);
+
+        assert_eq!(c.blend_screen(&white), white);
+        assert_eq!(c.blend_screen(&black), c);
+        assert_eq!(c.blend_screen(&grey), Colour::new(0.6, 0.8, 1.0));
+
+        assert_eq!(grey.blend_overlay(&white), white);
+        assert_eq!(grey.blend_overlay(&black), black);
+        assert_eq!(c.blend_overlay(&grey), c);
+        assert_eq!(c.blend_overlay(&Colour::new(0.8, 0.8, 0.8)), Colour::new(0.32, 0.84, 1.0));
+    }
+
+    #[test]
+    fn test_exact_eq_vs_abs_diff_eq() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(1.0, f64::from_bits(2.0f64.to_bits() + 1), 3.0);
+        assert!(a.exact_eq(&a));
+        assert!(!a.exact_eq(&b));
+        assert!(a.abs_diff_eq(&b, 1e-12));
+        assert!(!a.abs_diff_eq(&b, 0.0));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_lat_lon_round_trip() {
+        use std::f64::consts::FRAC_PI_2;
+        for (lat, lon) in [(0.0, 0.0), (0.0, 2.5), (0.0, -FRAC_PI_2), (0.7, -1.2)] {
+            let v = Vec3::from_lat_lon(lat, lon);
+            assert_abs_diff_eq!(v.mag(), 1.0, epsilon = 1e-12);
+            let (lat2, lon2) = v.to_lat_lon();
+            assert_abs_diff_eq!(lat2, lat, epsilon = 1e-12);
+            assert_abs_diff_eq!(lon2, lon, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_lat_lon_poles() {
+        use std::f64::consts::FRAC_PI_2;
+        assert_eq!(Vec3::from_lat_lon(FRAC_PI_2, 1.3), Vec3::UNIT_Y);
+        assert_eq!(Vec3::from_lat_lon(-FRAC_PI_2, 0.0), -Vec3::UNIT_Y);
+        assert_eq!(Vec3::new(0.0, 5.0, 0.0).to_lat_lon(), (FRAC_PI_2, 0.0));
+        assert_eq!((-Vec3::UNIT_Y).to_lat_lon(), (-FRAC_PI_2, 0.0));
+        assert_eq!(Vec3::ZERO.to_lat_lon(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_homogeneous_divide() {
+        let v = Vec3::new(2.0, 4.0, 6.0);
+        assert_eq!(v.homogeneous_divide(2.0), Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(v.homogeneous_divide(1.0), v);
+        assert_eq!(v.homogeneous_divide(-0.5), Vec3::new(-4.0, -8.0, -12.0));
+        assert_eq!(v.homogeneous_divide(0.0), v);
+    }
+
+    #[test]
+    fn test_clamp_to_aabb() {
+        let min = Point3D::new(-1.0, 0.0, 2.0);
+        let max = Point3D::new(1.0, 4.0, 3.0);
+        let inside = Point3D::new(0.5, 1.0, 2.5);
+        assert_eq!(inside.clamp_to_aabb(&min, &max), inside);
+        let outside = Point3D::new(5.0, 1.0, -7.0);
+        assert_eq!(outside.clamp_to_aabb(&min, &max), Point3D::new(1.0, 1.0, 2.0));
+    }
+
+    #[test]
+    fn test_normalize_and_length() {
+        let v = Vec3::new(-2.0, 7.5, 0.3);
+        let (dir, len) = v.normalize_and_length();
+        assert_eq!(dir, v.normalize());
+        assert_eq!(len, v.mag());
+        assert_eq!(Vec3::ZERO.normalize_and_length(), (Vec3::ZERO, 0.0));
+    }
+
+    #[test]
+    fn test_norms() {
+        let v = Vec3::new(-3.0, 2.0, -6.0);
+        assert_eq!(v.norm_inf(), 6.0);
+        assert_eq!(v.norm_l1(), 11.0);
+        assert_eq!(v.mag(), 7.0);
+        assert_eq!(Point3D::ZERO.distance_manhattan(&v), v.norm_l1());
+    }
+
+    #[test]
+    fn test_nlerp() {
+        let a = Vec3::new(1.0, 2.0, -2.0).normalize();
+        let b = Vec3::new(0.0, -3.0, 4.0).normalize();
+        assert_eq!(a.nlerp(&b, 0.0), a);
+        assert_eq!(a.nlerp(&b, 1.0), b);
+        for t in [0.1, 0.25, 0.5, 0.9] {
+            assert_abs_diff_eq!(a.nlerp(&b, t).mag(), 1.0, epsilon = 1e-12);
+        }
+        assert_eq!(Vec3::UNIT_X.nlerp(&-Vec3::UNIT_X, 0.5), Vec3::ZERO);
+    }
+
+    #[test]
+    fn test_cross_normalized() {
+        let a = Vec3::new(0.0, 0.0, 4.0);
+        let b = Vec3::new(0.5, 0.0, 0.0);
+        assert_eq!(a.cross_normalized(&b), Vec3::UNIT_Y);
+        assert_eq!(a.try_cross_normalized(&b), Some(Vec3::UNIT_Y));
+        assert_eq!(a.try_cross_normalized(&(-3.0 * a)), None);
+        assert_eq!(a.try_cross_normalized(&Vec3::ZERO), None);
+        assert!(a.cross_normalized(&(2.0 * a)).x.is_nan());
+    }
+
+    #[test]
+    fn test_plane_above_xz() {
+        let p = Point3D::new(2.0, 3.0, -1.0);
+        assert_eq!(p.distance_to_plane(&Point3D::ZERO, &Vec3::UNIT_Y), 3.0);
+        assert_eq!(p.closest_point_on_plane(&Point3D::ZERO, &Vec3::UNIT_Y), Point3D::new(2.0, 0.0, -1.0));
+        assert_eq!(p.distance_to_plane(&Point3D::ZERO, &-Vec3::UNIT_Y), -3.0);
+
+        let n = Vec3::new(1.0, 1.0, 0.0).normalize();
+        let q = p.closest_point_on_plane(&Point3D::UNIT_X, &n);
+        assert_abs_diff_eq!(q.distance_to_plane(&Point3D::UNIT_X, &n), 0.0, epsilon = 1e-12);
+    }
 }